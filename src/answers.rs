@@ -0,0 +1,190 @@
+use std::{fmt, fs, io};
+
+use crate::{
+    error::{Error, ParseError},
+    input, registry, Part,
+};
+
+pub const ANSWERS_PATH: &str = "inputs/answers.txt";
+
+const HEADER: &str = "# day part input answer";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// The known good answers, kept in a plain text file with one
+/// `day part input answer` line per answer.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (day, part, input, answer) = match fields[..] {
+                [day, part, input, answer] => (day, part, input, answer),
+                _ => {
+                    return Err(ParseError::new(
+                        index + 1,
+                        1,
+                        line,
+                        "expected `day part input answer`",
+                    ))
+                }
+            };
+
+            let day = day.parse::<u8>().map_err(|_| {
+                ParseError::at(index + 1, line, day, format!("invalid day `{}`", day))
+            })?;
+            let part = part.parse::<Part>().map_err(|_| {
+                ParseError::at(index + 1, line, part, format!("invalid part `{}`", part))
+            })?;
+
+            answers.set(day, part, input, answer);
+        }
+
+        Ok(answers)
+    }
+
+    /// A missing file is just an empty set of answers.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text).map_err(|e| e.in_file(path))?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|a| a.day == day && a.part == part && a.input == input)
+            .map(|a| a.answer.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, input: &str, answer: &str) {
+        match self
+            .answers
+            .iter_mut()
+            .find(|a| a.day == day && a.part == part && a.input == input)
+        {
+            Some(known) => known.answer = answer.to_owned(),
+            None => self.answers.push(Answer {
+                day,
+                part,
+                input: input.to_owned(),
+                answer: answer.to_owned(),
+            }),
+        }
+
+        self.answers
+            .sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Answer> {
+        self.answers.iter()
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+
+        for a in self.answers.iter() {
+            writeln!(f, "{} {} {} {}", a.day, a.part, a.input, a.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+    pub actual: Result<String, Error>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+}
+
+/// Re-runs every recorded answer.
+pub fn verify(answers: &Answers) -> Vec<Check> {
+    answers
+        .iter()
+        .map(|known| Check {
+            day: known.day,
+            part: known.part,
+            input: known.input.clone(),
+            expected: known.answer.clone(),
+            actual: solve(known.day, known.part, &known.input),
+        })
+        .collect()
+}
+
+pub fn solve(day: u8, part: Part, path: &str) -> Result<String, Error> {
+    let entry = registry::find(day)
+        .ok_or_else(|| Error::usage(format!("no solution registered for day {}", day)))?;
+    let input = input::load(path)?;
+    let mut result = entry.solve(&input, &[part]).map_err(|e| e.in_file(path))?;
+
+    Ok(result.parts.remove(0).answer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        let answers =
+            Answers::parse("# comment\n5 2 inputs/day5_test.txt MCD\n\n1 1 a.txt 3\n").unwrap();
+
+        assert_eq!(
+            answers.get(5, Part::Two, "inputs/day5_test.txt"),
+            Some("MCD")
+        );
+        assert_eq!(answers.get(5, Part::One, "inputs/day5_test.txt"), None);
+        assert_eq!(
+            answers.to_string(),
+            "# day part input answer\n1 1 a.txt 3\n5 2 inputs/day5_test.txt MCD\n"
+        );
+        assert!(Answers::parse("1 3 a.txt 3\n").is_err());
+    }
+
+    #[test]
+    fn recorded_answers_still_hold() {
+        let answers = Answers::load(ANSWERS_PATH).unwrap();
+
+        for check in verify(&answers) {
+            assert!(
+                check.passed(),
+                "day {} part {} on {} changed",
+                check.day,
+                check.part,
+                check.input
+            );
+        }
+    }
+}
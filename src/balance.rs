@@ -0,0 +1,364 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{self, Display},
+    mem,
+};
+
+/// Up to this many items are balanced exactly by default.
+pub const EXACT_LIMIT: usize = 20;
+
+/// The most elves to balance over, as every elf gets a load of its own.
+pub const MAX_ELVES: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    BranchAndBound,
+    Lpt,
+    KarmarkarKarp,
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Method::BranchAndBound => write!(f, "branch and bound (optimal)"),
+            Method::Lpt => write!(f, "longest processing time first"),
+            Method::KarmarkarKarp => write!(f, "Karmarkar-Karp differencing"),
+        }
+    }
+}
+
+/// Which items each elf carries, as indices into the balanced items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    pub method: Method,
+    pub elves: Vec<Vec<usize>>,
+    pub loads: Vec<u64>,
+    pub lower_bound: u64,
+}
+
+impl Schedule {
+    fn new(method: Method, items: &[u64], elves: Vec<Vec<usize>>) -> Self {
+        let loads = elves
+            .iter()
+            .map(|carried| carried.iter().map(|&item| items[item]).sum())
+            .collect();
+
+        Schedule {
+            method,
+            lower_bound: lower_bound(items, elves.len()),
+            elves,
+            loads,
+        }
+    }
+
+    /// The heaviest load, which balancing minimises.
+    pub fn makespan(&self) -> u64 {
+        self.loads.iter().copied().max().unwrap_or(0)
+    }
+
+    /// How far the heaviest load can at most be from the best possible one.
+    pub fn gap(&self) -> u64 {
+        self.makespan() - self.lower_bound
+    }
+}
+
+/// No elf can carry less than an even share of the total, or than the
+/// largest item.
+pub fn lower_bound(items: &[u64], elves: usize) -> u64 {
+    let total: u128 = items.iter().map(|&item| item as u128).sum();
+    let share = total.div_ceil(elves.max(1) as u128) as u64;
+
+    share.max(items.iter().copied().max().unwrap_or(0))
+}
+
+/// Spreads `items` over `elves` elves to make the heaviest load as light as
+/// possible: exactly for up to `exact_limit` items, or else with the better
+/// of the LPT and Karmarkar-Karp heuristics.
+pub fn balance(items: &[u64], elves: usize, exact_limit: usize) -> Schedule {
+    let elves = elves.max(1);
+    let lpt = lpt(items, elves);
+    let karmarkar_karp = karmarkar_karp(items, elves);
+    let best = if karmarkar_karp.makespan() < lpt.makespan() {
+        karmarkar_karp
+    } else {
+        lpt
+    };
+
+    if items.len() <= exact_limit {
+        branch_and_bound(items, elves, best)
+    } else {
+        best
+    }
+}
+
+/// Hands out the items largest first, each to the elf carrying the least.
+pub fn lpt(items: &[u64], elves: usize) -> Schedule {
+    let mut assigned = vec![vec![]; elves.max(1)];
+    let mut loads: BinaryHeap<Reverse<(u64, usize)>> =
+        (0..assigned.len()).map(|elf| Reverse((0, elf))).collect();
+
+    for item in by_size(items) {
+        let Reverse((load, elf)) = loads.pop().unwrap();
+        assigned[elf].push(item);
+        loads.push(Reverse((load + items[item], elf)));
+    }
+
+    Schedule::new(Method::Lpt, items, assigned)
+}
+
+/// A partial partition: its non-empty subsets as `(sum, items)`, heaviest
+/// first. The remaining subsets up to the number of elves are empty.
+type Partition = Vec<(u64, Vec<usize>)>;
+
+/// The multiway largest differencing method: every item starts as its own
+/// partition, and the two partitions whose heaviest and lightest subsets
+/// differ the most are merged, heaviest subsets with lightest ones, until
+/// one is left.
+pub fn karmarkar_karp(items: &[u64], elves: usize) -> Schedule {
+    let elves = elves.max(1);
+    let difference = |partition: &Partition| {
+        let lightest = match partition.len() {
+            len if len < elves => 0,
+            _ => partition.last().map_or(0, |subset| subset.0),
+        };
+
+        partition.first().map_or(0, |subset| subset.0) - lightest
+    };
+
+    let mut partitions: Vec<Partition> = (0..items.len())
+        .map(|item| vec![(items[item], vec![item])])
+        .collect();
+    let mut heap: BinaryHeap<(u64, usize)> = partitions
+        .iter()
+        .enumerate()
+        .map(|(index, partition)| (difference(partition), index))
+        .collect();
+
+    while heap.len() > 1 {
+        let (_, first) = heap.pop().unwrap();
+        let (_, second) = heap.pop().unwrap();
+        let mut first = mem::take(&mut partitions[first]);
+        let mut second = mem::take(&mut partitions[second]);
+
+        let size = elves.min(first.len() + second.len());
+        first.resize_with(size, Default::default);
+        second.resize_with(size, Default::default);
+
+        let mut merged: Partition = first
+            .into_iter()
+            .zip(second.into_iter().rev())
+            .map(|((sum, mut carried), (other_sum, other))| {
+                carried.extend(other);
+                (sum + other_sum, carried)
+            })
+            .filter(|(_, carried)| !carried.is_empty())
+            .collect();
+        merged.sort_by_key(|subset| Reverse(subset.0));
+
+        heap.push((difference(&merged), partitions.len()));
+        partitions.push(merged);
+    }
+
+    let mut assigned: Vec<Vec<usize>> = heap
+        .pop()
+        .map(|(_, index)| mem::take(&mut partitions[index]))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(_, carried)| carried)
+        .collect();
+    assigned.resize_with(elves, Vec::new);
+
+    Schedule::new(Method::KarmarkarKarp, items, assigned)
+}
+
+/// Tries every way to hand out the items, largest first, skipping the ones
+/// that can't beat `start` or the best one found so far.
+pub fn branch_and_bound(items: &[u64], elves: usize, start: Schedule) -> Schedule {
+    let elves = elves.max(1);
+    let order = by_size(items);
+    let mut search = Search {
+        sizes: order.iter().map(|&item| items[item]).collect(),
+        loads: vec![0; elves],
+        choices: vec![0; order.len()],
+        best: start.makespan(),
+        best_choices: None,
+        lower_bound: lower_bound(items, elves),
+    };
+    search.run(0);
+
+    // Nothing beat the start, which is then optimal.
+    let Some(choices) = search.best_choices else {
+        return Schedule {
+            method: Method::BranchAndBound,
+            ..start
+        };
+    };
+    let mut assigned = vec![vec![]; elves];
+    for (&item, &elf) in order.iter().zip(choices.iter()) {
+        assigned[elf].push(item);
+    }
+
+    Schedule::new(Method::BranchAndBound, items, assigned)
+}
+
+struct Search {
+    /// The items, largest first.
+    sizes: Vec<u64>,
+    loads: Vec<u64>,
+    /// The elf each item went to, so far.
+    choices: Vec<usize>,
+    best: u64,
+    best_choices: Option<Vec<usize>>,
+    lower_bound: u64,
+}
+
+impl Search {
+    fn run(&mut self, next: usize) {
+        if self.best == self.lower_bound {
+            return;
+        }
+        let Some(&size) = self.sizes.get(next) else {
+            self.best = self.loads.iter().copied().max().unwrap_or(0);
+            self.best_choices = Some(self.choices.clone());
+            return;
+        };
+
+        // Elves carrying the same load are interchangeable, so only one of
+        // them is tried.
+        let mut tried: Vec<u64> = vec![];
+        for elf in 0..self.loads.len() {
+            let load = self.loads[elf];
+            if load + size >= self.best || tried.contains(&load) {
+                continue;
+            }
+            tried.push(load);
+
+            self.loads[elf] += size;
+            self.choices[next] = elf;
+            self.run(next + 1);
+            self.loads[elf] -= size;
+        }
+    }
+}
+
+/// The indices of `items`, largest first.
+fn by_size(items: &[u64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&item| Reverse(items[item]));
+
+    order
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Method: {}", self.method)?;
+        writeln!(f, "Heaviest load: {}", self.makespan())?;
+        write!(
+            f,
+            "Lower bound: {} (gap {}, {:.2}%)",
+            self.lower_bound,
+            self.gap(),
+            self.gap() as f64 * 100.0 / self.lower_bound.max(1) as f64
+        )?;
+
+        for (elf, (carried, load)) in self.elves.iter().zip(self.loads.iter()).enumerate() {
+            write!(f, "\nelf {}: {} ({} items)", elf + 1, load, carried.len())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    /// Every item is carried by exactly one elf, and the loads add up.
+    fn assert_valid(schedule: &Schedule, items: &[u64], elves: usize) {
+        let mut carried: Vec<usize> = schedule.elves.iter().flatten().copied().collect();
+        carried.sort();
+
+        assert_eq!(schedule.elves.len(), elves);
+        assert_eq!(carried, (0..items.len()).collect::<Vec<usize>>());
+        assert_eq!(
+            schedule.loads.iter().sum::<u64>(),
+            items.iter().sum::<u64>()
+        );
+        assert!(schedule.makespan() >= schedule.lower_bound);
+    }
+
+    fn brute_force(items: &[u64], elves: usize) -> u64 {
+        let mut best = u64::MAX;
+        for mut code in 0..elves.pow(items.len() as u32) {
+            let mut loads = vec![0; elves];
+            for &item in items {
+                loads[code % elves] += item;
+                code /= elves;
+            }
+            best = best.min(*loads.iter().max().unwrap());
+        }
+
+        best
+    }
+
+    #[test]
+    fn lower_bound_test() {
+        assert_eq!(lower_bound(&[3, 3, 2, 2, 2], 2), 6);
+        assert_eq!(lower_bound(&[10, 1, 1], 3), 10);
+        assert_eq!(lower_bound(&[], 3), 0);
+    }
+
+    #[test]
+    fn heuristics_test() {
+        let items = [8, 7, 6, 5, 4];
+
+        assert_eq!(lpt(&items, 2).makespan(), 17);
+        assert_eq!(karmarkar_karp(&items, 2).makespan(), 16);
+
+        let exact = balance(&items, 2, EXACT_LIMIT);
+        assert_eq!(
+            (exact.method, exact.makespan()),
+            (Method::BranchAndBound, 15)
+        );
+        assert_valid(&exact, &items, 2);
+
+        let heuristic = balance(&items, 2, 3);
+        assert_eq!(
+            (heuristic.method, heuristic.makespan()),
+            (Method::KarmarkarKarp, 16)
+        );
+        assert_eq!(heuristic.gap(), 1);
+    }
+
+    #[test]
+    fn branch_and_bound_test() {
+        let mut rng = Rng::new(25);
+
+        for _ in 0..200 {
+            let elves = 1 + rng.below(3);
+            let items: Vec<u64> = (0..rng.below(8)).map(|_| rng.range(1..20)).collect();
+
+            let lpt = lpt(&items, elves);
+            let karmarkar_karp = karmarkar_karp(&items, elves);
+            let exact = balance(&items, elves, EXACT_LIMIT);
+            for schedule in [&lpt, &karmarkar_karp, &exact] {
+                assert_valid(schedule, &items, elves);
+            }
+
+            assert_eq!(exact.makespan(), brute_force(&items, elves), "{:?}", items);
+            assert!(exact.makespan() <= lpt.makespan().min(karmarkar_karp.makespan()));
+        }
+    }
+
+    #[test]
+    fn more_elves_than_items_test() {
+        let items = [5, 1];
+        let schedule = balance(&items, 4, EXACT_LIMIT);
+
+        assert_valid(&schedule, &items, 4);
+        assert_eq!(schedule.makespan(), 5);
+        assert_valid(&karmarkar_karp(&items, 4), &items, 4);
+    }
+}
@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use crate::{error::ParseError, registry::Entry, Part};
+
+pub const PHASES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+
+        Stats {
+            mean,
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: u8,
+    pub phases: [Stats; 3],
+}
+
+/// Runs the whole day `warmup` times without measuring, then collects the
+/// parse, part 1 and part 2 timings of `iterations` runs.
+pub fn bench(
+    entry: &Entry,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench, ParseError> {
+    for _ in 0..warmup {
+        entry.solve(input, &Part::ALL)?;
+    }
+
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    for _ in 0..iterations {
+        let result = entry.solve(input, &Part::ALL)?;
+
+        samples[0].push(result.parse_elapsed);
+        for (index, part) in result.parts.iter().enumerate() {
+            samples[index + 1].push(part.elapsed);
+        }
+    }
+
+    Ok(DayBench {
+        day: entry.day,
+        phases: samples.map(|phase| Stats::from_samples(&phase)),
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..=999 => format!("{} ns", nanos),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+pub fn table(benches: &[DayBench]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6} {:>11} {:>11} {:>11} {:>11}\n",
+        "Day", "Phase", "Mean", "Median", "Min", "Stddev"
+    );
+
+    for bench in benches.iter() {
+        for (phase, stats) in PHASES.iter().zip(bench.phases.iter()) {
+            table += &format!(
+                "{:>3}  {:<6} {:>11} {:>11} {:>11} {:>11}\n",
+                bench.day,
+                phase,
+                format_duration(stats.mean),
+                format_duration(stats.median),
+                format_duration(stats.min),
+                format_duration(stats.stddev),
+            );
+        }
+    }
+
+    let total: Duration = benches
+        .iter()
+        .flat_map(|bench| bench.phases.iter())
+        .map(|stats| stats.mean)
+        .sum();
+    table += &format!("Total mean: {}", format_duration(total));
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_input, registry};
+
+    #[test]
+    fn stats_test() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].map(Duration::from_micros).to_vec();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.stddev.as_nanos(), 2236);
+    }
+
+    #[test]
+    fn bench_test() {
+        let input = read_input("inputs/day1_test.txt");
+        let bench = bench(registry::find(1).unwrap(), &input, 1, 5).unwrap();
+
+        assert_eq!(bench.day, 1);
+        assert!(bench.phases.iter().all(|stats| stats.min <= stats.median));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    answers::{self, Answers},
+    error::Error,
+    registry, Part,
+};
+
+pub const START_MARKER: &str = "<!-- calendar:start -->";
+pub const END_MARKER: &str = "<!-- calendar:end -->";
+
+pub const DAYS: u8 = 25;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CalendarDay {
+    pub day: u8,
+    pub solved: bool,
+    /// Which parts have a verified answer for the day's real input.
+    pub stars: [bool; 2],
+}
+
+/// Builds the calendar from the registered solutions. A part earns its star
+/// once its answer on the real input is recorded and still verifies, and a
+/// day is ticked off once it has a star, so freshly scaffolded days are not.
+pub fn build(known: &Answers) -> Vec<CalendarDay> {
+    (1..=DAYS)
+        .map(|day| match registry::find(day) {
+            Some(entry) => {
+                let input = entry.default_input();
+                let stars = Part::ALL.map(|part| match known.get(day, part, &input) {
+                    Some(expected) => answers::solve(day, part, &input)
+                        .map(|actual| actual == expected)
+                        .unwrap_or(false),
+                    None => false,
+                });
+
+                CalendarDay {
+                    day,
+                    solved: stars.contains(&true),
+                    stars,
+                }
+            }
+            None => CalendarDay {
+                day,
+                solved: false,
+                stars: [false; 2],
+            },
+        })
+        .collect()
+}
+
+pub fn render(calendar: &[CalendarDay]) -> String {
+    let mut text = String::new();
+
+    for day in calendar.iter() {
+        let check = if day.solved { 'x' } else { ' ' };
+        let stars: String = day
+            .stars
+            .iter()
+            .filter(|&&star| star)
+            .map(|_| '⭐')
+            .collect();
+
+        text += &format!("- [{}] Day {}", check, day.day);
+        if !stars.is_empty() {
+            text += &format!(" {}", stars);
+        }
+        text.push('\n');
+    }
+
+    let total = calendar
+        .iter()
+        .flat_map(|day| day.stars.iter())
+        .filter(|&&star| star)
+        .count();
+    text += &format!("\nStars: {}/{}\n", total, DAYS as usize * 2);
+
+    text
+}
+
+/// Replaces whatever is between the calendar markers of `readme`.
+pub fn rewrite(readme: &str, calendar: &str) -> Result<String, Error> {
+    let missing = || {
+        Error::usage(format!(
+            "README has no `{}` ... `{}` section",
+            START_MARKER, END_MARKER
+        ))
+    };
+
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = readme[start..].find(END_MARKER).ok_or_else(missing)? + start;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        calendar,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let calendar = [
+            CalendarDay {
+                day: 1,
+                solved: true,
+                stars: [true, true],
+            },
+            CalendarDay {
+                day: 2,
+                solved: true,
+                stars: [true, false],
+            },
+            CalendarDay {
+                day: 3,
+                solved: false,
+                stars: [false, false],
+            },
+        ];
+
+        assert_eq!(
+            render(&calendar),
+            "- [x] Day 1 ⭐⭐\n- [x] Day 2 ⭐\n- [ ] Day 3\n\nStars: 3/50\n"
+        );
+    }
+
+    #[test]
+    fn rewrite_test() {
+        let readme = format!("# AoC\n{}\nold\n{}\nrest\n", START_MARKER, END_MARKER);
+
+        assert_eq!(
+            rewrite(&readme, "new\n").unwrap(),
+            format!("# AoC\n{}\n\nnew\n\n{}\nrest\n", START_MARKER, END_MARKER)
+        );
+        assert!(rewrite("# AoC\n", "new\n").is_err());
+    }
+
+    #[test]
+    fn build_test() {
+        let known = Answers::load(answers::ANSWERS_PATH).unwrap();
+        let calendar = build(&known);
+
+        assert_eq!(calendar.len(), 25);
+        assert!(calendar[7].solved && calendar[7].stars == [true, true]);
+        assert!(!calendar[8].solved);
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    cmp::Reverse,
+    fmt::{self, Display},
+};
+
+use crate::day1::Elf;
+
+/// The percentiles a report lists.
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+const BAR_WIDTH: usize = 40;
+
+/// Statistics of the calories the elves carry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Of the whole population of elves, not of a sample.
+    pub std_dev: f64,
+    /// Each of `PERCENTILES` with its nearest-rank value.
+    pub percentiles: Vec<(u8, u64)>,
+    pub histogram: Vec<Bucket>,
+    /// The elves with the most items as `(index, items)`, most first.
+    pub most_items: Vec<(usize, usize)>,
+}
+
+/// The elves whose total is in `from..=to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub from: u64,
+    pub to: u64,
+    pub elves: usize,
+}
+
+/// Up to `buckets` equally wide histogram buckets and the `most` elves with
+/// the most items, or `None` without any elves.
+pub fn report(elves: &[Elf], buckets: usize, most: usize) -> Option<Report> {
+    let mut totals: Vec<u64> = elves.iter().map(Elf::total).collect();
+    totals.sort_unstable();
+
+    let count = totals.len();
+    let (&min, &max) = (totals.first()?, totals.last()?);
+    let sum: u128 = totals.iter().map(|&total| total as u128).sum();
+    let mean = sum as f64 / count as f64;
+    let median = if count.is_multiple_of(2) {
+        (totals[count / 2 - 1] as f64 + totals[count / 2] as f64) / 2.0
+    } else {
+        totals[count / 2] as f64
+    };
+    let variance = totals
+        .iter()
+        .map(|&total| (total as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count as f64;
+
+    let percentiles = PERCENTILES
+        .iter()
+        .map(|&percentile| {
+            let rank = (percentile as usize * count).div_ceil(100).max(1);
+            (percentile, totals[rank - 1])
+        })
+        .collect();
+
+    let mut most_items: Vec<(usize, usize)> = elves
+        .iter()
+        .map(|elf| elf.items.len())
+        .enumerate()
+        .collect();
+    most_items.sort_by_key(|&(index, items)| (Reverse(items), index));
+    most_items.truncate(most);
+
+    Some(Report {
+        count,
+        min,
+        max,
+        mean,
+        median,
+        std_dev: variance.sqrt(),
+        percentiles,
+        histogram: histogram(&totals, min, max, buckets.max(1)),
+        most_items,
+    })
+}
+
+fn histogram(totals: &[u64], min: u64, max: u64, buckets: usize) -> Vec<Bucket> {
+    let width = ((max - min) / buckets as u64).saturating_add(1);
+    let mut histogram: Vec<Bucket> = (0..=(max - min) / width)
+        .map(|bucket| {
+            let from = min + bucket * width;
+
+            Bucket {
+                from,
+                to: from.saturating_add(width - 1).min(max),
+                elves: 0,
+            }
+        })
+        .collect();
+
+    for &total in totals {
+        histogram[((total - min) / width) as usize].elves += 1;
+    }
+
+    histogram
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.count)?;
+        writeln!(f, "Min: {}", self.min)?;
+        writeln!(f, "Max: {}", self.max)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        writeln!(f, "Std dev: {:.1}", self.std_dev)?;
+
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(percentile, value)| format!("p{} {}", percentile, value))
+            .collect();
+        writeln!(f, "Percentiles: {}", percentiles.join(", "))?;
+
+        writeln!(f, "Histogram:")?;
+        let largest = self.histogram.iter().map(|b| b.elves).max().unwrap_or(0);
+        let digits = self.max.to_string().len();
+        for bucket in self.histogram.iter() {
+            let bar = (bucket.elves * BAR_WIDTH).div_ceil(largest.max(1));
+            writeln!(
+                f,
+                "  {:>digits$} - {:>digits$} | {:<width$} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves,
+                digits = digits,
+                width = BAR_WIDTH,
+            )?;
+        }
+
+        write!(f, "Most items:")?;
+        for &(index, items) in self.most_items.iter() {
+            write!(f, "\n  elf {}: {} items", index + 1, items)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day1::Day1, read_input, Solution};
+
+    #[test]
+    fn report_test() {
+        let elves = Day1::parse(&read_input("inputs/day1_test.txt")).unwrap();
+        let report = report(&elves, 4, 2).unwrap();
+
+        assert_eq!((report.count, report.min, report.max), (5, 4000, 24000));
+        assert_eq!((report.mean, report.median), (11000.0, 10000.0));
+        assert!((report.std_dev - 6985.7).abs() < 0.1);
+        assert_eq!(
+            report.percentiles,
+            vec![
+                (10, 4000),
+                (25, 6000),
+                (50, 10000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(
+            report
+                .histogram
+                .iter()
+                .map(|bucket| bucket.elves)
+                .collect::<Vec<usize>>(),
+            vec![2, 2, 0, 1]
+        );
+        assert_eq!(report.histogram[3].to, 24000);
+        assert_eq!(report.most_items, vec![(0, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn small_report_test() {
+        let elves = vec![Elf { items: vec![5] }, Elf { items: vec![5] }];
+        let report = report(&elves, 10, 3).unwrap();
+
+        assert_eq!(report.median, 5.0);
+        assert_eq!(report.std_dev, 0.0);
+        assert_eq!(
+            report.histogram,
+            vec![Bucket {
+                from: 5,
+                to: 5,
+                elves: 2
+            }]
+        );
+        assert!(super::report(&[], 10, 3).is_none());
+    }
+}
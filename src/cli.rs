@@ -0,0 +1,94 @@
+use crate::error::Error;
+
+/// Minimal command line parser: options are taken out by name first, and
+/// whatever is left is read as positional arguments.
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: impl IntoIterator<Item = String>) -> Self {
+        Args {
+            args: args.into_iter().collect(),
+        }
+    }
+
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|a| a == name) {
+            Some(index) => {
+                self.args.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Takes `--name value` or `--name=value`.
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, Error> {
+        let prefix = format!("{}=", name);
+
+        for index in 0..self.args.len() {
+            if self.args[index] == name {
+                if index + 1 >= self.args.len() {
+                    return Err(Error::usage(format!("missing value for {}", name)));
+                }
+
+                self.args.remove(index);
+                return Ok(Some(self.args.remove(index)));
+            }
+
+            if let Some(value) = self.args[index].strip_prefix(&prefix) {
+                let value = value.to_owned();
+                self.args.remove(index);
+                return Ok(Some(value));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn positional(&mut self) -> Option<String> {
+        let index = self.args.iter().position(|a| !a.starts_with("--"))?;
+
+        Some(self.args.remove(index))
+    }
+
+    pub fn finish(self) -> Result<(), Error> {
+        match self.args.first() {
+            Some(arg) => Err(Error::usage(format!("unexpected argument: {}", arg))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Args {
+        Args::new(line.split_whitespace().map(|a| a.to_owned()))
+    }
+
+    #[test]
+    fn options_and_positionals_test() {
+        let mut args = args("run 7 --part 2 --input=foo.txt --all");
+
+        assert_eq!(args.positional().as_deref(), Some("run"));
+        assert_eq!(args.value("--part").unwrap().as_deref(), Some("2"));
+        assert_eq!(args.value("--input").unwrap().as_deref(), Some("foo.txt"));
+        assert!(args.flag("--all"));
+        assert!(!args.flag("--all"));
+        assert_eq!(args.positional().as_deref(), Some("7"));
+        assert!(args.finish().is_ok());
+    }
+
+    #[test]
+    fn leftovers_test() {
+        let mut args = args("list --verbose");
+
+        assert!(args.value("--part").unwrap().is_none());
+        assert_eq!(args.positional().as_deref(), Some("list"));
+        assert!(args.finish().is_err());
+        assert!(self::args("run --part").value("--part").is_err());
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{self, BufRead},
+    str,
+};
+
+use crate::{
+    error::{Error, ParseError},
+    generator::Generator,
+    oracle::Reference,
+    parser::{self, integer, preceded, space0, PResult, Span},
+    rng::Rng,
+    Solution,
+};
+
+const TOP_SIZE: usize = 3;
+
+const EXTRA_BLANK_LINE: &str = "unexpected blank line, expected the calories of an item";
+const OVERFLOW: &str = "too many calories to add up";
+
+/// The calories of the food items one elf carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    /// Saturates rather than overflowing, though parsed elves never get
+    /// there: `parse` rejects inputs whose calories don't all add up in a
+    /// `u64`.
+    pub fn total(&self) -> u64 {
+        self.items
+            .iter()
+            .fold(0, |total: u64, &item| total.saturating_add(item))
+    }
+}
+
+/// One of the elves carrying the most calories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopElf {
+    /// The elf's position in the input, from 0.
+    pub index: usize,
+    pub total: u64,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+    type Answer = u64;
+
+    /// One elf per block of lines with a single calories number each. Only
+    /// one empty line separates two elves.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let blocks = parser::blocks(input);
+        let mut elves = vec![];
+        let mut calories: u64 = 0;
+
+        for (index, block) in blocks.iter().enumerate() {
+            if block.lines.is_empty() {
+                // Only the input ending in a blank line leaves the last
+                // block empty.
+                if index + 1 == blocks.len() {
+                    break;
+                }
+                return Err(ParseError::new(block.first_line, 1, "", EXTRA_BLANK_LINE));
+            }
+
+            let items = block.parse_lines(item)?;
+            for ((line, text), &item) in block.numbered().zip(items.iter()) {
+                calories = calories
+                    .checked_add(item)
+                    .ok_or_else(|| ParseError::new(line, 1, text, OVERFLOW))?;
+            }
+
+            elves.push(Elf { items });
+        }
+
+        Ok(elves)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        top_k(input, 1).first().map_or(0, |elf| elf.total)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        top_k(input, TOP_SIZE).iter().map(|elf| elf.total).sum()
+    }
+}
+
+impl Generator for Day1 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                (0..rng.range(1..16))
+                    .map(|_| rng.range(1000..10000).to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n") + "\n"
+    }
+}
+
+impl Reference for Day1 {
+    fn reference_part1(input: &str) -> u64 {
+        reference_totals(input)[0]
+    }
+
+    fn reference_part2(input: &str) -> u64 {
+        reference_totals(input).iter().take(3).sum()
+    }
+}
+
+fn reference_totals(input: &str) -> Vec<u64> {
+    let mut totals: Vec<u64> = input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(|l| l.parse::<u64>().unwrap()).sum())
+        .collect();
+
+    totals.sort();
+    totals.reverse();
+
+    totals
+}
+
+fn item<'a>(span: Span<'a>) -> PResult<'a, u64> {
+    preceded(space0, integer("calories"))(span)
+}
+
+/// The `k` largest totals seen so far, kept on a heap with the order they
+/// came in. Of elves with the same total, the first ones win. The heap grows
+/// as elves come in, so it never holds more than `k + 1` or the number of
+/// elves seen, however large `k` is.
+struct TopK {
+    k: usize,
+    seen: usize,
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            seen: 0,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn push(&mut self, total: u64) {
+        self.heap.push(Reverse((total, Reverse(self.seen))));
+        self.seen += 1;
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Most first.
+    fn into_sorted(self) -> Vec<TopElf> {
+        let mut top: Vec<TopElf> = self
+            .heap
+            .into_iter()
+            .map(|Reverse((total, Reverse(index)))| TopElf { index, total })
+            .collect();
+        top.sort_by_key(|elf| (Reverse(elf.total), elf.index));
+
+        top
+    }
+}
+
+/// The `k` elves carrying the most calories, most first.
+pub fn top_k(elves: &[Elf], k: usize) -> Vec<TopElf> {
+    let mut top = TopK::new(k);
+    for elf in elves {
+        top.push(elf.total());
+    }
+
+    top.into_sorted()
+}
+
+/// Like `top_k` on the parsed `reader`, but reading it a line at a time and
+/// only keeping `k` elves, so memory doesn't grow with the input. The input
+/// is checked like `parse` does after loading it; `name` refers to it in
+/// errors.
+pub fn top_k_reader(mut reader: impl BufRead, name: &str, k: usize) -> Result<Vec<TopElf>, Error> {
+    let mut top = TopK::new(k);
+    let mut buffer = vec![];
+    let mut line = 0;
+    let mut current: Option<u64> = None;
+    // A blank line that isn't separating two elves, which is only fine if
+    // nothing but blank lines follow.
+    let mut extra_blank = None;
+    let mut calories: u64 = 0;
+
+    while read_line(&mut reader, &mut buffer).map_err(|e| Error::io(name, e))? {
+        line += 1;
+
+        let mut text = str::from_utf8(&buffer)
+            .map_err(|e| Error::io(name, io::Error::new(io::ErrorKind::InvalidData, e)))?;
+        if line == 1 {
+            text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        }
+
+        if text.is_empty() {
+            match current.take() {
+                Some(total) => top.push(total),
+                None => {
+                    extra_blank.get_or_insert(line);
+                }
+            }
+            continue;
+        }
+
+        if let Some(blank) = extra_blank {
+            return Err(ParseError::new(blank, 1, "", EXTRA_BLANK_LINE)
+                .in_file(name)
+                .into());
+        }
+
+        let item = parser::line(line, text, item).map_err(|e| e.in_file(name))?;
+        calories = calories
+            .checked_add(item)
+            .ok_or_else(|| ParseError::new(line, 1, text, OVERFLOW).in_file(name))?;
+        // Can't overflow, the total of all elves didn't.
+        *current.get_or_insert(0) += item;
+    }
+
+    if let Some(total) = current {
+        top.push(total);
+    }
+
+    Ok(top.into_sorted())
+}
+
+/// Reads the next line into `line` without its ending, which is `\n`, `\r\n`
+/// or a lone `\r` like `input::normalize` takes them. False at the end of
+/// the input.
+fn read_line(reader: &mut impl BufRead, line: &mut Vec<u8>) -> io::Result<bool> {
+    line.clear();
+
+    loop {
+        let available = match reader.fill_buf() {
+            Ok(available) => available,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if available.is_empty() {
+            return Ok(!line.is_empty());
+        }
+
+        match available.iter().position(|&b| b == b'\n' || b == b'\r') {
+            Some(end) => {
+                let ending = available[end];
+                line.extend_from_slice(&available[..end]);
+                reader.consume(end + 1);
+
+                if ending == b'\r' && reader.fill_buf()?.first() == Some(&b'\n') {
+                    reader.consume(1);
+                }
+                return Ok(true);
+            }
+            None => {
+                let read = available.len();
+                line.extend_from_slice(available);
+                reader.consume(read);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input, memory, read_input};
+
+    fn test_input() -> Vec<Elf> {
+        Day1::parse(&read_input("inputs/day1_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day1::part1(&test_input), 24000)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+        assert_eq!(Day1::part2(&test_input), 45000)
+    }
+
+    #[test]
+    fn top_k_test() {
+        let test_input = test_input();
+        let elf = |index, total| TopElf { index, total };
+
+        assert_eq!(
+            top_k(&test_input, 3),
+            vec![elf(3, 24000), elf(2, 11000), elf(4, 10000)]
+        );
+        assert_eq!(top_k(&test_input, 10).len(), 5);
+        assert!(top_k(&test_input, 0).is_empty());
+        assert_eq!(top_k(&test_input, usize::MAX).len(), 5);
+
+        let tied = Day1::parse("5\n\n7\n\n5\n\n5\n").unwrap();
+        assert_eq!(top_k(&tied, 2), vec![elf(1, 7), elf(0, 5)]);
+    }
+
+    #[test]
+    fn parse_test() {
+        let elves = test_input();
+
+        assert_eq!(elves.len(), 5);
+        assert_eq!(elves[0].items, vec![1000, 2000, 3000]);
+        assert_eq!(elves[3].total(), 24000);
+        assert_eq!(Day1::parse("").unwrap(), vec![]);
+        assert_eq!(Day1::parse("1\n\n2\n\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day1::parse("1000\n12a4\n\n2000\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "invalid calories `12a4`");
+
+        let error = Day1::parse("1000\n\n\n2000\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert!(Day1::parse("\n1000\n").is_err());
+
+        let error = Day1::parse("18446744073709551615\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert!(Day1::parse("18446744073709551616\n").is_err());
+    }
+
+    #[test]
+    fn top_k_reader_test() {
+        let input = read_input("inputs/day1_test.txt");
+        let top = top_k(&test_input(), 3);
+
+        assert_eq!(top_k_reader(input.as_bytes(), "test", 3).unwrap(), top);
+        assert_eq!(
+            top_k_reader(input.as_bytes(), "test", 100_000_000_000_000).unwrap(),
+            top_k(&test_input(), usize::MAX)
+        );
+
+        let crlf = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"));
+        assert_eq!(top_k_reader(crlf.as_bytes(), "test", 3).unwrap(), top);
+
+        let cr = input.replace('\n', "\r");
+        assert_eq!(top_k_reader(cr.as_bytes(), "test", 3).unwrap(), top);
+        // A line ending split across two reads of the buffer.
+        let crlf = cr.replace('\r', "\r\n");
+        let split = io::BufReader::with_capacity(5, crlf.as_bytes());
+        assert_eq!(top_k_reader(split, "test", 3).unwrap(), top);
+
+        for bad in [
+            "1000\n12a4\n",
+            "1000\n\n\n2000\n",
+            "1000\r\r\r2000\r",
+            "\n1",
+            "18446744073709551615\n\n1\n",
+        ] {
+            let Err(Error::Parse(error)) = top_k_reader(bad.as_bytes(), "bad", 3) else {
+                panic!("{:?} parsed", bad);
+            };
+            let expected = Day1::parse(&input::normalize(bad)).unwrap_err();
+
+            assert_eq!((error.line, error.column), (expected.line, expected.column));
+            assert_eq!(error.file.as_deref(), Some("bad"));
+        }
+    }
+
+    #[test]
+    fn top_k_reader_memory_test() {
+        let input = Day1::generate(&mut Rng::new(1), 20_000);
+        let (top, stats) = memory::measure(|| top_k_reader(input.as_bytes(), "big", 3).unwrap());
+
+        assert_eq!(top, top_k(&Day1::parse(&input).unwrap(), 3));
+        if memory::ENABLED {
+            assert!(stats.peak < 1024, "peak of {} bytes", stats.peak);
+        }
+    }
+}
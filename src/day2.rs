@@ -0,0 +1,216 @@
+use crate::{
+    error::ParseError,
+    generator::Generator,
+    oracle::Reference,
+    parser::{self, PResult, Span},
+    rng::Rng,
+    Solution,
+};
+
+#[derive(PartialEq, Clone, Copy)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    fn from_code(letter_code: char) -> Self {
+        match letter_code {
+            move_code if move_code == 'A' || move_code == 'X' => Self::Rock,
+            move_code if move_code == 'B' || move_code == 'Y' => Self::Paper,
+            _ => Self::Scissors,
+        }
+    }
+
+    fn play(&self, move2: &Self) -> u32 {
+        match (self, move2) {
+            (move1, move2) if move1 == move2 => 3,
+            (Self::Rock, Self::Paper) => 6,
+            (Self::Paper, Self::Scissors) => 6,
+            (Self::Scissors, Self::Rock) => 6,
+            _ => 0,
+        }
+    }
+
+    fn points(&self) -> u32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    fn winning_response(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    fn losing_response(&self) -> Self {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(char, char)>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::lines(input, |span| {
+            let (op_move, span) = code(span, &['A', 'B', 'C'])?;
+            let (response, span) = code(span, &['X', 'Y', 'Z'])?;
+
+            Ok(((op_move, response), span))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let mut total_points: u32 = 0;
+        for (op_move_code, response_code) in input.iter() {
+            let response = Move::from_code(*response_code);
+            let round_points = Move::from_code(*op_move_code).play(&response) + response.points();
+
+            total_points += round_points;
+        }
+
+        total_points
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        input
+            .iter()
+            .map(|(op_move_code, result_code)| {
+                let op_move = Move::from_code(*op_move_code);
+
+                let response = match result_code {
+                    'X' => op_move.losing_response(),
+                    'Y' => op_move,
+                    _ => op_move.winning_response(),
+                };
+
+                op_move.play(&response) + response.points()
+            })
+            .sum()
+    }
+}
+
+impl Generator for Day2 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let op_move = rng.choose(&['A', 'B', 'C']);
+                let response = rng.choose(&['X', 'Y', 'Z']);
+
+                format!("{} {}\n", op_move, response)
+            })
+            .collect()
+    }
+}
+
+impl Reference for Day2 {
+    fn reference_part1(input: &str) -> u32 {
+        reference_rounds(input)
+            .map(|round| match round.as_str() {
+                "A X" => 1 + 3,
+                "A Y" => 2 + 6,
+                "A Z" => 3,
+                "B X" => 1,
+                "B Y" => 2 + 3,
+                "B Z" => 3 + 6,
+                "C X" => 1 + 6,
+                "C Y" => 2,
+                "C Z" => 3 + 3,
+                _ => panic!("invalid round {}", round),
+            })
+            .sum()
+    }
+
+    fn reference_part2(input: &str) -> u32 {
+        reference_rounds(input)
+            .map(|round| match round.as_str() {
+                "A X" => 3,
+                "A Y" => 1 + 3,
+                "A Z" => 2 + 6,
+                "B X" => 1,
+                "B Y" => 2 + 3,
+                "B Z" => 3 + 6,
+                "C X" => 2,
+                "C Y" => 3 + 3,
+                "C Z" => 1 + 6,
+                _ => panic!("invalid round {}", round),
+            })
+            .sum()
+    }
+}
+
+fn reference_rounds(input: &str) -> impl Iterator<Item = String> + '_ {
+    input
+        .lines()
+        .map(|l| l.split_whitespace().collect::<Vec<&str>>().join(" "))
+}
+
+/// A single-letter move out of `codes`.
+fn code<'a>(span: Span<'a>, codes: &[char]) -> PResult<'a, char> {
+    let expected = codes
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let (code, rest) = parser::word("move")(span).map_err(|error| ParseError {
+        message: format!("missing move, expected one of {}", expected),
+        ..error
+    })?;
+
+    let mut chars = code.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if codes.contains(&c) => Ok((c, rest)),
+        _ => Err(span.error_at(
+            code,
+            format!("invalid move `{}`, expected one of {}", code, expected),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> Vec<(char, char)> {
+        Day2::parse(&read_input("inputs/day2_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day2::part1(&test_input), 15)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day2::part2(&test_input), 12)
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day2::parse("A Y\nB Q\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "B Q");
+    }
+}
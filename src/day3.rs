@@ -0,0 +1,256 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::ParseError, generator::Generator, oracle::Reference, parser, rng::Rng, Solution,
+};
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<char>>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks: Vec<Vec<char>> = parser::lines(input, |span| {
+            let (items, rest) = parser::take_while(|c| ALPHABET.contains(c))(span)?;
+
+            if let Some(item) = rest.rest().chars().next() {
+                return Err(rest.error(format!("invalid item `{}`, expected a letter", item)));
+            }
+            if items.len() % 2 != 0 {
+                return Err(rest.error(format!(
+                    "odd number of items ({}) can't be split in two compartments",
+                    items.len()
+                )));
+            }
+
+            let (first, second) = items.split_at(items.len() / 2);
+            if !first.chars().any(|item| second.contains(item)) {
+                return Err(span.error("no item is in both compartments"));
+            }
+
+            Ok((items.chars().collect(), rest))
+        })?;
+
+        let lines: Vec<&str> = input.lines().collect();
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(ParseError::at_end(
+                lines.len(),
+                lines[lines.len() - 1],
+                format!(
+                    "expected whole groups of three rucksacks, got {} rucksacks",
+                    rucksacks.len()
+                ),
+            ));
+        }
+        for (group, chunk) in rucksacks.chunks(3).enumerate() {
+            if !chunk[0]
+                .iter()
+                .any(|item| chunk[1].contains(item) && chunk[2].contains(item))
+            {
+                let line = group * 3 + 3;
+
+                return Err(ParseError::new(
+                    line,
+                    1,
+                    lines[line - 1],
+                    "no badge is shared by this group of three rucksacks",
+                ));
+            }
+        }
+
+        Ok(rucksacks)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .map(|line| {
+                let (first, second) = line.split_at(line.len() / 2);
+
+                let repeated = get_repeated(first, second);
+
+                get_priority(repeated)
+            })
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .chunks(3)
+            .map(|chunk| {
+                let repeated: Vec<char> = chunk[0]
+                    .iter()
+                    .copied()
+                    .filter(|&c| chunk[1].contains(&c))
+                    .collect();
+
+                let badge = get_repeated(&repeated, &chunk[2]);
+
+                get_priority(badge)
+            })
+            .sum()
+    }
+}
+
+/// `size` is the number of rucksacks, rounded up to whole groups of three.
+/// Every elf of a group draws its items from its own pool of letters, so the
+/// badge is the only item shared by the group.
+impl Generator for Day3 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let items: Vec<char> = ALPHABET.chars().collect();
+        let mut rucksacks = String::new();
+
+        for _ in 0..size.div_ceil(3).max(1) {
+            let mut letters = items.clone();
+            rng.shuffle(&mut letters);
+            let badge = letters.pop().unwrap();
+
+            for pool in letters.chunks(letters.len() / 3) {
+                rucksacks += &generate_rucksack(rng, badge, pool);
+                rucksacks.push('\n');
+            }
+        }
+
+        rucksacks
+    }
+}
+
+impl Reference for Day3 {
+    fn reference_part1(input: &str) -> usize {
+        input
+            .lines()
+            .map(|l| {
+                let (first, second) = l.split_at(l.len() / 2);
+                let first: HashSet<char> = first.chars().collect();
+                let second: HashSet<char> = second.chars().collect();
+
+                first
+                    .intersection(&second)
+                    .map(|&c| reference_priority(c))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        let rucksacks: Vec<HashSet<char>> = input.lines().map(|l| l.chars().collect()).collect();
+
+        rucksacks
+            .chunks(3)
+            .map(|group| {
+                group[0]
+                    .iter()
+                    .filter(|c| group[1].contains(c) && group[2].contains(c))
+                    .map(|&c| reference_priority(c))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+fn reference_priority(item: char) -> usize {
+    if item.is_ascii_lowercase() {
+        item as usize - 'a' as usize + 1
+    } else {
+        item as usize - 'A' as usize + 27
+    }
+}
+
+/// Both compartments only have `repeated` in common; `badge` goes in one of
+/// them unless it is the repeated item itself.
+fn generate_rucksack(rng: &mut Rng, badge: char, pool: &[char]) -> String {
+    let repeated = if rng.chance(1, 4) { badge } else { pool[0] };
+    let (left_pool, right_pool) = pool[1..].split_at(pool.len() / 2);
+    let compartment_size = rng.range(4..17) as usize;
+
+    let mut left = vec![repeated];
+    let mut right = vec![repeated];
+
+    if repeated != badge {
+        if rng.chance(1, 2) {
+            left.push(badge);
+        } else {
+            right.push(badge);
+        }
+    }
+
+    while left.len() < compartment_size {
+        left.push(*rng.choose(left_pool));
+    }
+    while right.len() < compartment_size {
+        right.push(*rng.choose(right_pool));
+    }
+
+    rng.shuffle(&mut left);
+    rng.shuffle(&mut right);
+
+    left.into_iter().chain(right).collect()
+}
+
+fn get_repeated(first: &[char], second: &[char]) -> char {
+    first
+        .iter()
+        .find(|&&c| second.contains(&c))
+        .unwrap()
+        .to_owned()
+}
+
+fn get_priority(element: char) -> usize {
+    let priorities: Vec<(usize, char)> = ALPHABET.char_indices().collect();
+
+    let (index, _) = priorities
+        .iter()
+        .find(|(_index, letter)| element == *letter)
+        .unwrap();
+
+    *index + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> Vec<Vec<char>> {
+        Day3::parse(&read_input("inputs/day3_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day3::part1(&test_input), 157)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day3::part2(&test_input), 70)
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day3::parse("abca\nab1d\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert!(Day3::parse("abc\n").is_err());
+
+        let error = Day3::parse("abcd\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        let error = Day3::parse("aa\nbb\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Day3::parse("aa\nbb\ncc\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (3, "no badge is shared by this group of three rucksacks")
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::ParseError,
+    generator::Generator,
+    oracle::Reference,
+    parser::{self, integer, map_res, tag, terminated, PResult, Span},
+    rng::Rng,
+    Solution,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<[u16; 4]>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parser::lines(input, |span| {
+            let (first, span) = terminated(range, tag(","))(span)?;
+            let (second, span) = range(span)?;
+
+            Ok(([first.0, first.1, second.0, second.1], span))
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .filter(|[r1_start, r1_end, r2_start, r2_end]| {
+                r1_start <= r2_start && r1_end >= r2_end || r2_start <= r1_start && r2_end >= r1_end
+            })
+            .count()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .iter()
+            .filter(|[r1_start, r1_end, r2_start, r2_end]| {
+                (r1_start <= r2_start && r2_start <= r1_end)
+                    || (r2_start <= r1_start && r1_start <= r2_end)
+            })
+            .count()
+    }
+}
+
+impl Generator for Day4 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let (r1_start, r1_end) = generate_range(rng);
+                let (r2_start, r2_end) = generate_range(rng);
+
+                format!("{}-{},{}-{}\n", r1_start, r1_end, r2_start, r2_end)
+            })
+            .collect()
+    }
+}
+
+impl Reference for Day4 {
+    fn reference_part1(input: &str) -> usize {
+        reference_pairs(input)
+            .iter()
+            .filter(|(first, second)| first.is_subset(second) || second.is_subset(first))
+            .count()
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        reference_pairs(input)
+            .iter()
+            .filter(|(first, second)| !first.is_disjoint(second))
+            .count()
+    }
+}
+
+fn reference_pairs(input: &str) -> Vec<(HashSet<u16>, HashSet<u16>)> {
+    let sections = |range: &str| -> HashSet<u16> {
+        let (start, end) = range.split_once('-').unwrap();
+
+        (start.parse().unwrap()..=end.parse().unwrap()).collect()
+    };
+
+    input
+        .lines()
+        .map(|l| {
+            let (first, second) = l.split_once(',').unwrap();
+
+            (sections(first), sections(second))
+        })
+        .collect()
+}
+
+fn generate_range(rng: &mut Rng) -> (u64, u64) {
+    let start = rng.range(1..100);
+
+    (start, rng.range(start..100))
+}
+
+/// A range of sections, `a-b`.
+fn range(span: Span) -> PResult<(u16, u16)> {
+    map_res(bounds, |(start, end)| {
+        if start <= end {
+            Ok((start, end))
+        } else {
+            Err(format!(
+                "invalid range `{}-{}`, it ends before it starts",
+                start, end
+            ))
+        }
+    })(span)
+}
+
+fn bounds(span: Span) -> PResult<(u16, u16)> {
+    let section = integer::<u16>("section");
+    let (start, span) = terminated(&section, tag("-"))(span)?;
+    let (end, span) = section(span)?;
+
+    Ok(((start, end), span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> Vec<[u16; 4]> {
+        Day4::parse(&read_input("inputs/day4_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day4::part1(&test_input), 2)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day4::part2(&test_input), 4)
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day4::parse("2-4,6-8\n2-x,4-5\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "2-x,4-5");
+        assert!(Day4::parse("2-4,6\n").is_err());
+
+        let error = Day4::parse("2-4,6-8\n2-4,15-3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(
+            error.message,
+            "invalid range `15-3`, it ends before it starts"
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use crate::{
+    error::ParseError,
+    generator::Generator,
+    oracle::Reference,
+    parser::{self, integer, map_res, preceded, space0, space1, tag, Block, Span},
+    rng::Rng,
+    Solution,
+};
+
+#[derive(Debug)]
+pub struct Movement {
+    pub from: usize,
+    pub to: usize,
+    pub amount: usize,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Vec<char>>, Vec<Movement>);
+    type Answer = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let blocks = parser::blocks(input);
+        let drawing = &blocks[0];
+
+        let Some(moves) = blocks.get(1) else {
+            return Err(ParseError::at_end(
+                drawing.lines.len().max(1),
+                drawing.lines.last().copied().unwrap_or_default(),
+                "missing blank line between the crates drawing and the moves",
+            ));
+        };
+        if let Some(extra) = blocks[2..].iter().find(|block| !block.lines.is_empty()) {
+            return Err(ParseError::new(
+                extra.first_line,
+                1,
+                extra.lines[0],
+                "unexpected blank line between the moves",
+            ));
+        }
+
+        let state = parse_state(drawing)?;
+        let movements = parse_moves(moves, &state)?;
+
+        Ok((state, movements))
+    }
+
+    fn part1((state, movements): &Self::Input) -> String {
+        let mut state = state.clone();
+
+        for m in movements.iter() {
+            for _ in 0..m.amount {
+                let crate_code = state[m.from - 1].pop().unwrap();
+
+                state[m.to - 1].push(crate_code);
+            }
+        }
+
+        // An empty stack has no crate on top to tell.
+        state.iter().filter_map(|stack| stack.last()).collect()
+    }
+
+    fn part2((state, movements): &Self::Input) -> String {
+        let mut state = state.clone();
+
+        for m in movements.iter() {
+            let new_length = state[m.from - 1].len() - m.amount;
+            let mut crates_to_move = state[m.from - 1].split_off(new_length);
+
+            state[m.to - 1].append(&mut crates_to_move);
+        }
+
+        state.iter().filter_map(|stack| stack.last()).collect()
+    }
+}
+
+/// `size` is the number of moves. Moves never empty a stack, so both parts
+/// always have a crate on top of every stack.
+impl Generator for Day5 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut state: Vec<Vec<char>> = (0..rng.range(3..10))
+            .map(|_| {
+                (0..rng.range(1..9))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        if state.iter().all(|stack| stack.len() == 1) {
+            state[0].push('Z');
+        }
+
+        let height = state.iter().map(|stack| stack.len()).max().unwrap();
+        let mut drawing = String::new();
+
+        for level in (0..height).rev() {
+            let row: Vec<String> = state
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(crate_code) => format!("[{}]", crate_code),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+
+            drawing += &row.join(" ");
+            drawing.push('\n');
+        }
+
+        let labels: Vec<String> = (1..=state.len()).map(|n| format!(" {} ", n)).collect();
+        drawing += &labels.join(" ");
+        drawing += "\n\n";
+
+        for _ in 0..size {
+            let sources: Vec<usize> = (0..state.len())
+                .filter(|&stack| state[stack].len() > 1)
+                .collect();
+            let from = *rng.choose(&sources);
+            let to = (from + 1 + rng.below(state.len() - 1)) % state.len();
+            let amount = rng.range(1..state[from].len() as u64) as usize;
+
+            let new_length = state[from].len() - amount;
+            let mut crates_to_move = state[from].split_off(new_length);
+            state[to].append(&mut crates_to_move);
+
+            drawing += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+        }
+
+        drawing
+    }
+}
+
+impl Reference for Day5 {
+    fn reference_part1(input: &str) -> String {
+        let (mut stacks, moves) = reference_parse(input);
+
+        for (amount, from, to) in moves {
+            for _ in 0..amount {
+                let crate_code = stacks[from - 1].pop().unwrap();
+                stacks[to - 1].push(crate_code);
+            }
+        }
+
+        stacks.iter().map(|stack| stack[stack.len() - 1]).collect()
+    }
+
+    fn reference_part2(input: &str) -> String {
+        let (mut stacks, moves) = reference_parse(input);
+
+        for (amount, from, to) in moves {
+            let mut lifted = vec![];
+            for _ in 0..amount {
+                lifted.push(stacks[from - 1].pop().unwrap());
+            }
+            for _ in 0..amount {
+                stacks[to - 1].push(lifted.pop().unwrap());
+            }
+        }
+
+        stacks.iter().map(|stack| stack[stack.len() - 1]).collect()
+    }
+}
+
+type ReferenceMove = (usize, usize, usize);
+
+fn reference_parse(input: &str) -> (Vec<Vec<char>>, Vec<ReferenceMove>) {
+    let lines: Vec<&str> = input.lines().collect();
+    let blank = lines.iter().position(|l| l.is_empty()).unwrap();
+    let stack_count = lines[blank - 1].split_whitespace().count();
+
+    let mut stacks = vec![vec![]; stack_count];
+    for row in lines[..blank - 1].iter().rev() {
+        let row: Vec<char> = row.chars().collect();
+
+        for (stack, crates) in stacks.iter_mut().enumerate() {
+            if let Some(&c) = row.get(stack * 4 + 1) {
+                if c != ' ' {
+                    crates.push(c);
+                }
+            }
+        }
+    }
+
+    let moves = lines[blank + 1..]
+        .iter()
+        .map(|l| {
+            let words: Vec<&str> = l.split(' ').collect();
+
+            (
+                words[1].parse().unwrap(),
+                words[3].parse().unwrap(),
+                words[5].parse().unwrap(),
+            )
+        })
+        .collect();
+
+    (stacks, moves)
+}
+
+fn parse_state(drawing: &Block) -> Result<Vec<Vec<char>>, ParseError> {
+    let (&labels, rows) = drawing
+        .lines
+        .split_last()
+        .ok_or_else(|| ParseError::new(1, 1, "", "missing stack labels"))?;
+    let label_line = drawing.first_line + rows.len();
+
+    let mut state: Vec<Vec<char>> = vec![];
+    let mut span = Span::new(label_line, labels);
+    while let Ok((label, rest)) = parser::word("stack label")(span) {
+        if label.parse::<usize>() != Ok(state.len() + 1) {
+            return Err(span.error_at(
+                label,
+                format!(
+                    "invalid stack label `{}`, expected {}",
+                    label,
+                    state.len() + 1
+                ),
+            ));
+        }
+
+        state.push(vec![]);
+        span = rest;
+    }
+
+    if state.is_empty() {
+        return Err(ParseError::at_end(
+            label_line,
+            labels,
+            "missing stack labels",
+        ));
+    }
+
+    for (index, &row) in rows.iter().enumerate().rev() {
+        let cells = parser::cells(row, state.len(), 1, 4);
+
+        for ((column, cell), stack) in cells.into_iter().zip(&mut state) {
+            match cell {
+                Some(value) if value.is_alphabetic() => stack.push(value),
+                Some(' ') | None => (),
+                Some(value) => {
+                    return Err(ParseError::new(
+                        drawing.first_line + index,
+                        column,
+                        row,
+                        format!("invalid crate `{}`", value),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+/// Also follows the height of every stack, so no move takes more crates than
+/// its stack has.
+fn parse_moves(moves: &Block, state: &[Vec<char>]) -> Result<Vec<Movement>, ParseError> {
+    let stacks = state.len();
+    let mut heights: Vec<usize> = state.iter().map(Vec::len).collect();
+    let keyword = |word| preceded(space1, tag(word));
+    let stack = || {
+        map_res(preceded(space1, integer::<usize>("stack")), move |stack| {
+            if (1..=stacks).contains(&stack) {
+                Ok(stack)
+            } else {
+                Err(format!(
+                    "stack {} doesn't exist, there are {} stacks",
+                    stack, stacks
+                ))
+            }
+        })
+    };
+
+    moves
+        .numbered()
+        .map(|(number, text)| {
+            parser::line(number, text, |span| {
+                let (_, span) = preceded(space0, tag("move"))(span)?;
+                let (_, amount_span) = space1(span)?;
+                let (amount, span) = integer("number")(amount_span)?;
+                let (from, span) = preceded(keyword("from"), stack())(span)?;
+                let (to, span) = preceded(keyword("to"), stack())(span)?;
+
+                if amount > heights[from - 1] {
+                    return Err(amount_span.error(format!(
+                        "can't move {} crates, stack {} only has {}",
+                        amount,
+                        from,
+                        heights[from - 1]
+                    )));
+                }
+                heights[from - 1] -= amount;
+                heights[to - 1] += amount;
+
+                Ok((Movement { from, to, amount }, span))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> (Vec<Vec<char>>, Vec<Movement>) {
+        Day5::parse(&read_input("inputs/day5_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day5::part1(&test_input), "CMZ")
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day5::part2(&test_input), "MCD")
+    }
+
+    #[test]
+    fn empty_stack_test() {
+        let input = Day5::parse("[A]\n 1   2\n\nmove 1 from 1 to 2\n").unwrap();
+
+        assert_eq!(Day5::part1(&input), "A");
+        assert_eq!(Day5::part2(&input), "A");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 1 from 2 to 7\n";
+        let error = Day5::parse(input).unwrap_err();
+
+        assert_eq!((error.line, error.column), (6, 18));
+        assert!(Day5::parse("[A]\n 1\n").is_err());
+
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 2 from 2 to 1\nmove 4 from 1 to 2\n";
+        let error = Day5::parse(input).unwrap_err();
+        assert_eq!((error.line, error.column), (6, 6));
+        assert_eq!(error.message, "can't move 4 crates, stack 1 only has 3");
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::ParseError, generator::Generator, oracle::Reference, parser, rng::Rng, Solution,
+};
+
+const PACKET_MARKER: usize = 4;
+const MESSAGE_MARKER: usize = 14;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Answer = usize;
+
+    /// The signal is a single line; the newline ending it isn't part of it.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut signals = parser::lines(
+            input,
+            parser::take_while1("signal", |c| c.is_ascii_lowercase()),
+        )?;
+
+        let signal = match signals.len() {
+            0 => return Err(ParseError::new(1, 1, "", "missing signal")),
+            1 => signals.remove(0),
+            _ => {
+                return Err(ParseError::new(
+                    2,
+                    1,
+                    input.lines().nth(1).unwrap_or_default(),
+                    "expected a single line",
+                ))
+            }
+        };
+
+        // Any start of message marker holds a start of packet one too.
+        if signal.len() < MESSAGE_MARKER {
+            return Err(ParseError::at_end(
+                1,
+                signal,
+                format!(
+                    "signal too short ({} characters), expected at least {}",
+                    signal.len(),
+                    MESSAGE_MARKER
+                ),
+            ));
+        }
+        if puzzle(signal, MESSAGE_MARKER).is_none() {
+            return Err(ParseError::new(
+                1,
+                1,
+                signal,
+                format!(
+                    "no start of message marker of {} different characters",
+                    MESSAGE_MARKER
+                ),
+            ));
+        }
+
+        Ok(signal.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        puzzle(input, PACKET_MARKER).unwrap_or(input.len())
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        puzzle(input, MESSAGE_MARKER).unwrap_or(input.len())
+    }
+}
+
+/// `size` is the length of the signal, which always contains a start of
+/// message marker somewhere.
+impl Generator for Day6 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let length = size.max(14);
+        let mut signal: Vec<char> = (0..length)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+
+        let mut marker: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut marker);
+        let start = rng.below(length - 13);
+        signal[start..start + 14].copy_from_slice(&marker[..14]);
+
+        signal.into_iter().collect()
+    }
+}
+
+impl Reference for Day6 {
+    fn reference_part1(input: &str) -> usize {
+        reference_marker(input, 4)
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        reference_marker(input, 14)
+    }
+}
+
+fn reference_marker(input: &str, marker: usize) -> usize {
+    let chars: Vec<char> = input.trim_end().chars().collect();
+
+    for end in marker..=chars.len() {
+        let window: HashSet<char> = chars[end - marker..end].iter().copied().collect();
+
+        if window.len() == marker {
+            return end;
+        }
+    }
+
+    chars.len()
+}
+
+/// Where the first `marker` different characters in a row end, if anywhere.
+fn puzzle(input: &str, marker: usize) -> Option<usize> {
+    let last_start = input.len().checked_sub(marker)?;
+
+    for i in 0..=last_start {
+        let current = &input[i..i + marker];
+
+        let all_diff = current.chars().enumerate().all(|(i, c)| {
+            let repeated = current
+                .chars()
+                .enumerate()
+                .take_while(|(i2, _)| *i2 != i)
+                .any(|(_, c2)| c2 == c);
+
+            !repeated
+        });
+
+        if all_diff {
+            return Some(i + marker);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input_1 = "bvwbjplbgvbhsrlpgdmjqwftvncz".to_owned();
+        let test_input_2 = "nppdvjthqldpwncqszvftbrmjlhg".to_owned();
+        let test_input_3 = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_owned();
+        let test_input_4 = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_owned();
+
+        assert_eq!(Day6::part1(&test_input_1), 5);
+        assert_eq!(Day6::part1(&test_input_2), 6);
+        assert_eq!(Day6::part1(&test_input_3), 10);
+        assert_eq!(Day6::part1(&test_input_4), 11);
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input_1 = "mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_owned();
+        let test_input_2 = "bvwbjplbgvbhsrlpgdmjqwftvncz".to_owned();
+        let test_input_3 = "nppdvjthqldpwncqszvftbrmjlhg".to_owned();
+        let test_input_4 = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_owned();
+        let test_input_5 = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_owned();
+
+        assert_eq!(Day6::part2(&test_input_1), 19);
+        assert_eq!(Day6::part2(&test_input_2), 23);
+        assert_eq!(Day6::part2(&test_input_3), 23);
+        assert_eq!(Day6::part2(&test_input_4), 29);
+        assert_eq!(Day6::part2(&test_input_5), 26);
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Day6::parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")
+                .unwrap()
+                .len(),
+            30
+        );
+
+        let error = Day6::parse("abcd1efgh\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert!(Day6::parse("").is_err());
+        assert!(Day6::parse("abcdefghijklmn\nabcd\n").is_err());
+
+        let error = Day6::parse("abc\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        let error = Day6::parse("abcdabcdabcdabcdabcd\n").unwrap_err();
+        assert_eq!(
+            error.message,
+            "no start of message marker of 14 different characters"
+        );
+    }
+}
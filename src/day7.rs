@@ -0,0 +1,400 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    error::ParseError,
+    generator::Generator,
+    oracle::Reference,
+    parser::{self, integer, preceded, space0, word, PResult, Span},
+    rng::Rng,
+    Solution,
+};
+
+/// The id of `/`. Its parent, 0, isn't a directory.
+const ROOT: usize = 1;
+
+#[derive(Debug)]
+struct AocFile {
+    id: usize,
+    name: String,
+    dir: usize,
+    size: usize,
+    is_dir: bool,
+}
+
+impl AocFile {}
+
+struct System {
+    current_dir: usize,
+    files: Vec<AocFile>,
+    file_id_count: usize,
+}
+
+impl System {
+    fn get_next_id(&mut self) -> usize {
+        self.file_id_count += 1;
+
+        self.file_id_count
+    }
+
+    fn mkdir(&mut self, name: &str) {
+        let file = AocFile {
+            id: self.get_next_id(),
+            name: name.to_owned(),
+            size: 0,
+            dir: self.current_dir,
+            is_dir: true,
+        };
+
+        self.files.push(file)
+    }
+
+    fn add_file(&mut self, name: &str, size: usize) {
+        let file = AocFile {
+            id: self.get_next_id(),
+            name: name.to_owned(),
+            size,
+            dir: self.current_dir,
+            is_dir: false,
+        };
+
+        self.files.push(file)
+    }
+    fn cd(&mut self, path: &str) -> Result<(), String> {
+        match path {
+            "/" => self.current_dir = ROOT,
+            ".." => match self.files.iter_mut().find(|f| f.id == self.current_dir) {
+                Some(dir) => self.current_dir = dir.dir,
+                _ => return Err(format!("parent dir of {} not found", self.current_dir)),
+            },
+            _ => match self
+                .files
+                .iter()
+                .find(|f| f.name == path && f.dir == self.current_dir)
+            {
+                Some(dir) => self.current_dir = dir.id,
+                _ => return Err(format!("dir `{}` not found", path)),
+            },
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<usize, (usize, usize)>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        list_directories(input)
+    }
+
+    fn part1(directories: &Self::Input) -> usize {
+        directories
+            .iter()
+            .filter(|&(_k, v)| v.0 <= 100_000)
+            .map(|(_k, v)| v.0)
+            .sum()
+    }
+
+    fn part2(directories: &Self::Input) -> usize {
+        let total_disk_space: usize = 70000000;
+        let required_space_for_update: usize = 30000000;
+        let (used_space, _parent) = directories[&ROOT];
+
+        let free_space = total_disk_space.saturating_sub(used_space);
+        let needed_free_space = required_space_for_update.saturating_sub(free_space);
+
+        directories
+            .values()
+            .filter(|(size, _)| *size >= needed_free_space)
+            .map(|(size, _)| *size)
+            .min()
+            .unwrap()
+    }
+}
+
+/// `size` is the number of directories. The disk usage mostly lands between
+/// 41M and 69M so part 2 has something to free, and otherwise is small
+/// enough for most directories to count in part 1.
+impl Generator for Day7 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let dirs = size.max(1);
+        let mut children: Vec<Vec<usize>> = vec![vec![]; dirs];
+        for dir in 1..dirs {
+            children[rng.below(dir)].push(dir);
+        }
+
+        let mut names: Vec<HashSet<String>> = vec![HashSet::new(); dirs];
+        let mut dir_names: Vec<String> = vec!["/".to_owned(); dirs];
+        for (parent, dir_children) in children.iter().enumerate() {
+            for &child in dir_children.iter() {
+                dir_names[child] = generate_name(rng, &mut names[parent], false);
+            }
+        }
+
+        let mut files: Vec<Vec<(String, u64)>> = vec![vec![]; dirs];
+        let mut total_weight = 0;
+        for (dir, dir_files) in files.iter_mut().enumerate() {
+            for _ in 0..rng.range(0..5) {
+                let weight = rng.range(1..1000);
+                total_weight += weight;
+                dir_files.push((generate_name(rng, &mut names[dir], true), weight));
+            }
+        }
+
+        let disk_usage = if rng.chance(1, 4) {
+            rng.range(1..1_000_000)
+        } else {
+            rng.range(41_000_000..69_000_000)
+        };
+        let mut used = 0;
+        for (_, size) in files.iter_mut().flatten() {
+            *size = (*size * (disk_usage - 1) / total_weight).max(1);
+            used += *size;
+        }
+        if used < disk_usage {
+            let name = generate_name(rng, &mut names[0], true);
+            files[0].push((name, disk_usage - used));
+        }
+
+        let mut transcript = String::from("$ cd /\n");
+        write_dir(&mut transcript, rng, 0, &children, &dir_names, &files);
+
+        transcript
+    }
+}
+
+impl Reference for Day7 {
+    fn reference_part1(input: &str) -> usize {
+        reference_sizes(input)
+            .values()
+            .filter(|&&size| size <= 100_000)
+            .sum()
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        let sizes = reference_sizes(input);
+        let used_space = sizes[&vec![]];
+        let needed_free_space = 30_000_000usize.saturating_sub(70_000_000 - used_space);
+
+        *sizes
+            .values()
+            .filter(|&&size| size >= needed_free_space)
+            .min()
+            .unwrap()
+    }
+}
+
+/// Total size of every directory, keyed by its path from the root.
+fn reference_sizes(input: &str) -> HashMap<Vec<&str>, usize> {
+    let mut cwd: Vec<&str> = vec![];
+    let mut sizes: HashMap<Vec<&str>, usize> = HashMap::new();
+    sizes.insert(vec![], 0);
+
+    for line in input.lines() {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop();
+            }
+            ["$", "cd", dir] => cwd.push(dir),
+            ["$", "ls"] => (),
+            ["dir", dir] => {
+                let mut path = cwd.clone();
+                path.push(dir);
+                sizes.entry(path).or_insert(0);
+            }
+            [size, _name] => {
+                let size: usize = size.parse().unwrap();
+
+                for depth in 0..=cwd.len() {
+                    *sizes.entry(cwd[..depth].to_vec()).or_insert(0) += size;
+                }
+            }
+            _ => panic!("invalid line {}", line),
+        }
+    }
+
+    sizes
+}
+
+fn generate_name(rng: &mut Rng, taken: &mut HashSet<String>, is_file: bool) -> String {
+    loop {
+        let mut name: String = (0..rng.range(1..9))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+
+        if is_file && rng.chance(1, 2) {
+            name += *rng.choose(&[".txt", ".dat", ".log", ".lst", ".ext"]);
+        }
+
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn write_dir(
+    transcript: &mut String,
+    rng: &mut Rng,
+    dir: usize,
+    children: &[Vec<usize>],
+    dir_names: &[String],
+    files: &[Vec<(String, u64)>],
+) {
+    let mut entries: Vec<String> = children[dir]
+        .iter()
+        .map(|&child| format!("dir {}", dir_names[child]))
+        .chain(
+            files[dir]
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    rng.shuffle(&mut entries);
+
+    *transcript += "$ ls\n";
+    for entry in entries.iter() {
+        *transcript += entry;
+        transcript.push('\n');
+    }
+
+    for &child in children[dir].iter() {
+        *transcript += &format!("$ cd {}\n", dir_names[child]);
+        write_dir(transcript, rng, child, children, dir_names, files);
+        *transcript += "$ cd ..\n";
+    }
+}
+
+fn list_directories(input: &str) -> Result<HashMap<usize, (usize, usize)>, ParseError> {
+    let mut system = System {
+        current_dir: 0,
+        files: vec![],
+        file_id_count: 0,
+    };
+
+    system.mkdir("/");
+    system.current_dir = ROOT;
+    for (index, l) in input.lines().enumerate() {
+        parser::line(index + 1, l, |span| run_line(&mut system, span))?;
+    }
+
+    // Every directory with its own size and its parent.
+    let mut group: HashMap<usize, (usize, usize)> = system
+        .files
+        .iter()
+        .filter(|f| f.is_dir)
+        .map(|f| (f.id, (0, f.dir)))
+        .collect();
+
+    for f in system.files.iter().filter(|f| !f.is_dir) {
+        if let Some(dir) = group.get_mut(&f.dir) {
+            dir.0 += f.size;
+        }
+    }
+
+    let mut sorted_groups = group.keys().copied().collect::<Vec<usize>>();
+
+    sorted_groups.sort_by_key(|v| *v);
+
+    // Subdirectories are made after their parent, so their ids are larger
+    // and their sizes are complete by the time they're added to it.
+    for dir_id in sorted_groups.iter().rev() {
+        let (dir_size, parent_id) = group[dir_id];
+
+        group
+            .entry(parent_id)
+            .and_modify(|value| value.0 += dir_size);
+    }
+
+    Ok(group)
+}
+
+fn run_line<'a>(system: &mut System, span: Span<'a>) -> PResult<'a, ()> {
+    let (first, rest) = word("command")(span).map_err(|_| span.error("empty line"))?;
+
+    match first {
+        "$" => run_command(system, rest),
+        "dir" => {
+            let (dir_name, rest) = word("dir name")(rest)?;
+            system.mkdir(dir_name);
+
+            Ok(((), rest))
+        }
+        _ => {
+            let (file_size, rest) = preceded(space0, integer("file size"))(span)?;
+            let (file_name, rest) = word("file name")(rest)?;
+            system.add_file(file_name, file_size);
+
+            Ok(((), rest))
+        }
+    }
+}
+
+fn run_command<'a>(system: &mut System, span: Span<'a>) -> PResult<'a, ()> {
+    let (command, rest) = word("command")(span)?;
+
+    match command {
+        "cd" => {
+            let (dir_to_find, rest) = word("dir to cd into")(rest)?;
+
+            system
+                .cd(dir_to_find)
+                .map(|()| ((), rest))
+                .map_err(|message| rest.error_at(dir_to_find, message))
+        }
+        "ls" => Ok(((), rest)),
+        invalid_command => Err(span.error_at(
+            invalid_command,
+            format!("invalid command `{}`", invalid_command),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> HashMap<usize, (usize, usize)> {
+        Day7::parse(&read_input("inputs/day7_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        assert_eq!(Day7::part1(&test_input()), 95437);
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        assert_eq!(Day7::part2(&test_input()), 24933642);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day7::parse("$ cd /\n$ ls\n12a b\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 1));
+        assert!(Day7::parse("$ cd /\n$ cd nowhere\n").is_err());
+
+        let small = Day7::parse("$ cd /\n$ ls\n10 a\n").unwrap();
+        assert_eq!(Day7::part2(&small), 10);
+        assert_eq!(Day7::part2(&Day7::parse("$ cd /\n").unwrap()), 0);
+    }
+
+    #[test]
+    fn small_tree_test() {
+        let input = "$ cd /\n$ ls\n10 a\ndir b\n$ cd b\n$ ls\n5 c\n";
+        let directories = Day7::parse(input).unwrap();
+
+        assert_eq!(Day7::part1(&directories), Day7::reference_part1(input));
+        assert_eq!(Day7::part1(&directories), 20);
+        assert_eq!(Day7::part2(&directories), Day7::reference_part2(input));
+    }
+}
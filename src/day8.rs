@@ -0,0 +1,178 @@
+use crate::{
+    error::ParseError,
+    generator::Generator,
+    grid::{Direction, Grid, Pos},
+    oracle::Reference,
+    rng::Rng,
+    Solution,
+};
+
+type Forest = Grid<u32>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "tree height", |c| c.to_digit(10))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let mut visible = input.map(|_| false);
+
+        // Walks every line of sight in from the edges, marking the trees
+        // taller than all the ones before them.
+        for direction in Direction::ORTHOGONAL {
+            for start in input.edge(direction) {
+                let mut tallest = input[start];
+                visible[start] = true;
+
+                for pos in input.ray_positions(start, direction) {
+                    if input[pos] > tallest {
+                        tallest = input[pos];
+                        visible[pos] = true;
+                    }
+                }
+            }
+        }
+
+        visible.iter().filter(|&&tree| tree).count()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .positions()
+            .map(|pos| {
+                Direction::ORTHOGONAL
+                    .into_iter()
+                    .map(|direction| viewing_distance(input, pos, direction))
+                    .product()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+/// `size` is the height of the forest, its width is random up to `size`.
+impl Generator for Day8 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = 1 + rng.below(size.max(1));
+
+        (0..size.max(1))
+            .map(|_| {
+                let mut line: String = (0..width)
+                    .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                    .collect();
+                line.push('\n');
+
+                line
+            })
+            .collect()
+    }
+}
+
+impl Reference for Day8 {
+    fn reference_part1(input: &str) -> usize {
+        let forest = reference_forest(input);
+
+        reference_trees(&forest)
+            .filter(|&(row, column)| {
+                reference_rays(&forest, row, column)
+                    .iter()
+                    .any(|ray| ray.iter().all(|&tree| tree < forest[row][column]))
+            })
+            .count()
+    }
+
+    fn reference_part2(input: &str) -> usize {
+        let forest = reference_forest(input);
+
+        reference_trees(&forest)
+            .map(|(row, column)| {
+                reference_rays(&forest, row, column)
+                    .iter()
+                    .map(
+                        |ray| match ray.iter().position(|&tree| tree >= forest[row][column]) {
+                            Some(blocked) => blocked + 1,
+                            None => ray.len(),
+                        },
+                    )
+                    .product()
+            })
+            .max()
+            .unwrap()
+    }
+}
+
+fn reference_forest(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+fn reference_trees(forest: &[Vec<u32>]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (0..forest.len()).flat_map(move |row| (0..forest[row].len()).map(move |column| (row, column)))
+}
+
+/// The trees seen looking up, down, left and right, nearest first.
+fn reference_rays(forest: &[Vec<u32>], row: usize, column: usize) -> [Vec<u32>; 4] {
+    [
+        (0..row).rev().map(|r| forest[r][column]).collect(),
+        (row + 1..forest.len()).map(|r| forest[r][column]).collect(),
+        (0..column).rev().map(|c| forest[row][c]).collect(),
+        (column + 1..forest[row].len())
+            .map(|c| forest[row][c])
+            .collect(),
+    ]
+}
+
+/// How many trees can be seen from `pos` before one at least as tall.
+fn viewing_distance(forest: &Forest, pos: Pos, direction: Direction) -> usize {
+    let mut distance = 0;
+
+    for &tree in forest.ray(pos, direction) {
+        distance += 1;
+        if tree >= forest[pos] {
+            break;
+        }
+    }
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> Forest {
+        Day8::parse(&read_input("inputs/day8_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day8::part1(&test_input), 21)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day8::part2(&test_input), 8)
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Day8::parse("123\n1x3\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert!(Day8::parse("123\n12\n").is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use std::{error, fmt, io};
+
+/// A malformed puzzle input, pointing at the offending line and column
+/// (both 1-based).
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Points at `token`, which has to be a slice of `text`. Anything else
+    /// (e.g. a missing token) points just past the end of the line.
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= text.len())
+            .unwrap_or(text.len());
+
+        Self::new(line, text[..offset].chars().count() + 1, text, message)
+    }
+
+    /// Points just past the end of the line, for lines that end too early.
+    pub fn at_end(line: usize, text: &str, message: impl Into<String>) -> Self {
+        Self::new(line, text.chars().count() + 1, text, message)
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = self.line.to_string().len();
+
+        writeln!(
+            f,
+            "{}:{}:{}: {}",
+            file, self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse(ParseError),
+    Usage(String),
+    /// A result that doesn't match what it was checked against.
+    Mismatch(String),
+    /// A request to a remote server that failed or was refused.
+    Http(String),
+}
+
+impl Error {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        Error::Io {
+            path: path.to_owned(),
+            source,
+        }
+    }
+
+    pub fn usage(message: impl Into<String>) -> Self {
+        Error::Usage(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Parse(error) => write!(f, "{}", error),
+            Error::Usage(message) | Error::Mismatch(message) | Error::Http(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(error) => Some(error),
+            Error::Usage(_) | Error::Mismatch(_) | Error::Http(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_token_test() {
+        let line = "2-x,4-5";
+        let error = ParseError::at(3, line, &line[2..3], "invalid number `x`");
+
+        assert_eq!(error.column, 3);
+        assert_eq!(
+            error.in_file("inputs/day4.txt").to_string(),
+            "inputs/day4.txt:3:3: invalid number `x`\n  |\n3 | 2-x,4-5\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn at_missing_token_test() {
+        let error = ParseError::at(1, "A", "", "missing response");
+
+        assert_eq!(error.column, 2);
+    }
+}
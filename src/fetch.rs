@@ -0,0 +1,235 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{error::Error, http, input};
+
+/// The session cookie of the account whose inputs are fetched.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Overrides the server inputs are fetched from.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const YEAR: u16 = 2022;
+
+/// The shortest time allowed between two requests to the server.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Remembers when the last request was sent, next to the cached inputs.
+const STAMP_FILE: &str = ".last-request";
+
+/// Where the session is read from when the variable isn't set.
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/aoc22/session"))
+}
+
+/// The session from the environment or the session file, if any.
+pub fn session() -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(session_file()?).ok())
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+}
+
+/// The `Cookie` header value for `session`. A line break in it would end
+/// the header and start another one.
+pub fn cookie(session: &str) -> Result<String, Error> {
+    if session.contains(['\r', '\n']) {
+        return Err(Error::usage("invalid session: it contains a line break"));
+    }
+
+    Ok(format!("session={}", session))
+}
+
+pub fn base_url() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned())
+}
+
+/// Downloads personal puzzle inputs into the input directory, which doubles
+/// as the cache: a day that has a non-empty input there is never fetched.
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Fetcher {
+    pub fn from_env() -> Self {
+        Fetcher {
+            base_url: base_url(),
+            session: session(),
+            cache_dir: input::input_dir(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(format!("day{}.txt", day))
+    }
+
+    /// An empty file, as left by `aoc22 new`, doesn't count as cached.
+    pub fn is_cached(&self, day: u8) -> bool {
+        fs::metadata(self.cache_path(day)).is_ok_and(|metadata| metadata.len() > 0)
+    }
+
+    /// Returns the path of the day's input, fetching it first if needed.
+    pub fn ensure(&self, day: u8) -> Result<PathBuf, Error> {
+        let path = self.cache_path(day);
+
+        if !self.is_cached(day) {
+            let input = self.fetch(day)?;
+            fs::create_dir_all(&self.cache_dir)
+                .map_err(|e| Error::io(&self.cache_dir.display().to_string(), e))?;
+            fs::write(&path, input).map_err(|e| Error::io(&path.display().to_string(), e))?;
+        }
+
+        Ok(path)
+    }
+
+    fn fetch(&self, day: u8) -> Result<String, Error> {
+        let session = self.session.as_deref().ok_or_else(|| {
+            Error::usage(format!(
+                "{} is missing or empty; set {} to fetch it",
+                self.cache_path(day).display(),
+                SESSION_VAR
+            ))
+        })?;
+        let cookie = cookie(session)?;
+
+        self.wait_for_turn()?;
+
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            YEAR,
+            day
+        );
+        let response = http::get(&url, &[("Cookie", &cookie)])?;
+
+        match response.status {
+            _ if response.is_success() => Ok(response.body),
+            404 => Err(Error::Http(format!("day {} is not unlocked yet", day))),
+            400 | 401 | 500 => Err(Error::Http(format!(
+                "fetching day {} failed with status {}, is the session still valid?",
+                day, response.status
+            ))),
+            status => Err(Error::Http(format!(
+                "fetching day {} failed with status {}: {}",
+                day,
+                status,
+                response.body.lines().next().unwrap_or_default()
+            ))),
+        }
+    }
+
+    /// Sleeps until `min_interval` has passed since the previous request,
+    /// then records this one.
+    fn wait_for_turn(&self) -> Result<(), Error> {
+        let stamp = self.cache_dir.join(STAMP_FILE);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|nanos| nanos.trim().parse::<u64>().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok()) {
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        fs::create_dir_all(&self.cache_dir)
+            .and_then(|()| fs::write(&stamp, now.to_string()))
+            .map_err(|e| Error::io(&stamp.display().to_string(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{process, time::Instant};
+
+    use super::*;
+    use crate::http::testing;
+
+    fn fetcher(name: &str, base_url: String) -> Fetcher {
+        let cache_dir = env::temp_dir().join(format!("aoc22-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        Fetcher {
+            base_url,
+            session: Some("secret".to_owned()),
+            cache_dir,
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn fetch_and_cache_test() {
+        let (base_url, server) = testing::serve(vec![(200, "1000\n\n2000\n")]);
+        let fetcher = fetcher("cache", base_url);
+
+        let path = fetcher.ensure(1).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n\n2000\n");
+        // The server only answers once, so this must come from the cache.
+        assert_eq!(fetcher.ensure(1).unwrap(), path);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+    }
+
+    #[test]
+    fn locked_day_test() {
+        let (base_url, server) = testing::serve(vec![(404, "Not found")]);
+        let fetcher = fetcher("locked", base_url);
+
+        assert!(matches!(fetcher.ensure(25), Err(Error::Http(_))));
+        assert!(!fetcher.is_cached(25));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn rate_limit_test() {
+        let (base_url, server) = testing::serve(vec![(200, "A Y\n"), (200, "vJrwpWtwJgWr\n")]);
+        let fetcher = Fetcher {
+            min_interval: Duration::from_millis(200),
+            ..fetcher("rate", base_url)
+        };
+
+        let start = Instant::now();
+        fetcher.ensure(2).unwrap();
+        fetcher.ensure(3).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn cookie_test() {
+        assert_eq!(cookie("secret").unwrap(), "session=secret");
+        assert!(matches!(
+            cookie("secret\r\nX-Injected: 1"),
+            Err(Error::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn missing_session_test() {
+        let fetcher = Fetcher {
+            session: None,
+            ..fetcher("session", "http://127.0.0.1:1".to_owned())
+        };
+
+        assert!(matches!(fetcher.ensure(1), Err(Error::Usage(_))));
+    }
+}
@@ -0,0 +1,7 @@
+use crate::rng::Rng;
+
+/// Produces random, syntactically valid puzzle input for a day. What `size`
+/// counts depends on the day (elves, rounds, moves, grid side, ...).
+pub trait Generator {
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
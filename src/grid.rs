@@ -0,0 +1,406 @@
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+/// A cell of a grid, as `(row, column)`.
+pub type Pos = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// The change in `(row, column)` of one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (-1, 1),
+            Direction::DownLeft => (1, -1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// `None` unless all rows have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character, line by line, with `cell` telling the
+    /// valid characters apart. `what` names a cell in error messages.
+    pub fn parse(
+        input: &str,
+        what: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::new(
+                        index + 1,
+                        column + 1,
+                        line,
+                        format!("invalid {} `{}`", what, c),
+                    )
+                })?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::at_end(
+                        index + 1,
+                        line,
+                        format!("row is {} wide, expected {}", row_width, width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new(1, 1, "", "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    /// All cells in row order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All positions in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// The neighbouring cell in `direction`, if it is inside the grid.
+    pub fn step(&self, (row, column): Pos, direction: Direction) -> Option<Pos> {
+        let (rows, columns) = direction.offset();
+        let pos = (
+            row.checked_add_signed(rows)?,
+            column.checked_add_signed(columns)?,
+        );
+
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The up to 8 neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The positions from `pos` (excluded) to the edge in `direction`,
+    /// nearest first.
+    pub fn ray_positions(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        let mut current = pos;
+
+        std::iter::from_fn(move || {
+            current = self.step(current, direction)?;
+            Some(current)
+        })
+    }
+
+    /// The cells from `pos` (excluded) to the edge in `direction`, nearest
+    /// first.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = &T> + '_ {
+        self.ray_positions(pos, direction)
+            .map(move |pos| &self[pos])
+    }
+
+    /// The positions on the edge that rays in `direction` start from, such
+    /// as the left column for `Right`.
+    pub fn edge(&self, direction: Direction) -> Vec<Pos> {
+        let (rows, columns) = direction.offset();
+
+        self.positions()
+            .filter(|&(row, column)| {
+                (rows == 1 && row == 0)
+                    || (rows == -1 && row + 1 == self.height)
+                    || (columns == 1 && column == 0)
+                    || (columns == -1 && column + 1 == self.width)
+            })
+            .collect()
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (self.height - 1 - column, row)
+        })
+    }
+
+    /// Turns the grid a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (column, self.width - 1 - row)
+        })
+    }
+
+    /// A `width` by `height` grid whose cell at `pos` comes from `source(pos)`
+    /// in this one.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("position outside the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("position outside the grid")
+    }
+}
+
+/// A grid of characters, one row per line.
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, "cell", Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef\n".parse().unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+
+        let error = "ab\nabc\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = Grid::parse("12\n3x\n", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn neighbours_test() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<Pos>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn ray_test() {
+        let grid = grid();
+
+        assert_eq!(grid.ray((0, 0), Direction::Right).collect::<String>(), "bc");
+        assert_eq!(grid.ray((1, 2), Direction::Left).collect::<String>(), "ed");
+        assert_eq!(
+            grid.ray((1, 0), Direction::UpRight).collect::<String>(),
+            "b"
+        );
+        assert_eq!(grid.ray((0, 0), Direction::Up).count(), 0);
+        assert_eq!(grid.edge(Direction::Down), vec![(0, 0), (0, 1), (0, 2)]);
+        assert_eq!(grid.edge(Direction::Left), vec![(0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn transform_test() {
+        let grid = grid();
+        let text = |grid: &Grid<char>| {
+            grid.rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+
+        assert_eq!(text(&grid.transpose()), "ad\nbe\ncf");
+        assert_eq!(text(&grid.rotate_right()), "da\neb\nfc");
+        assert_eq!(text(&grid.rotate_left()), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,341 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use crate::error::Error;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+const USER_AGENT: &str = concat!("aoc22/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends a GET request. Plain `http://` URLs are spoken to directly over a
+/// socket; `https://` ones go through the `curl` command, as std has no TLS.
+pub fn get(url: &str, headers: &[(&str, &str)]) -> Result<Response, Error> {
+    request("GET", url, headers, None)
+}
+
+/// Sends a POST request with a form encoded `body`, see `get`.
+pub fn post(url: &str, headers: &[(&str, &str)], body: &str) -> Result<Response, Error> {
+    let mut headers = headers.to_vec();
+    headers.push(("Content-Type", "application/x-www-form-urlencoded"));
+
+    request("POST", url, &headers, Some(body))
+}
+
+/// Percent-encodes `value` for a form body.
+pub fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    if url.starts_with("https://") {
+        return curl(method, url, headers, body);
+    }
+
+    let (host, path) = split_url(url)?;
+    let failed = |e: std::io::Error| Error::Http(format!("{} {}: {}", method, url, e));
+
+    let mut stream = TcpStream::connect(address(host)).map_err(failed)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+    stream.set_write_timeout(Some(TIMEOUT)).map_err(failed)?;
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n",
+        method, path, host, USER_AGENT
+    );
+    for (name, value) in headers {
+        request += &format!("{}: {}\r\n", name, value);
+    }
+    if let Some(body) = body {
+        request += &format!("Content-Length: {}\r\n", body.len());
+    }
+    request += "\r\n";
+    request += body.unwrap_or_default();
+
+    stream.write_all(request.as_bytes()).map_err(failed)?;
+    let mut raw = vec![];
+    stream.read_to_end(&mut raw).map_err(failed)?;
+
+    parse_response(&raw)
+        .ok_or_else(|| Error::Http(format!("{} {}: malformed response", method, url)))
+}
+
+/// Splits `http://host[:port]/path` into the host and the path to request.
+fn split_url(url: &str) -> Result<(&str, &str), Error> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| Error::usage(format!("unsupported URL: {}", url)))?;
+
+    Ok(match rest.find('/') {
+        Some(slash) => (&rest[..slash], &rest[slash..]),
+        None => (rest, "/"),
+    })
+}
+
+/// The address to connect to for `host`, on port 80 unless it names one.
+fn address(host: &str) -> String {
+    match host.rsplit_once(':') {
+        // The colons of an IPv6 address like `[::1]` don't start a port.
+        Some((_, port)) if !port.ends_with(']') => host.to_owned(),
+        _ => format!("{}:80", host),
+    }
+}
+
+fn parse_response(raw: &[u8]) -> Option<Response> {
+    let split = raw.windows(4).position(|window| window == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&raw[..split]);
+    let mut body = raw[split + 4..].to_vec();
+
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?.parse().ok()?;
+
+    for line in lines {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+
+        if name.eq_ignore_ascii_case("transfer-encoding") && value.eq_ignore_ascii_case("chunked") {
+            body = dechunk(&body)?;
+        } else if name.eq_ignore_ascii_case("content-length") {
+            body.truncate(value.parse().ok()?);
+        }
+    }
+
+    Some(Response {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn dechunk(mut raw: &[u8]) -> Option<Vec<u8>> {
+    let mut body = vec![];
+
+    loop {
+        let end = raw.windows(2).position(|window| window == b"\r\n")?;
+        let size = String::from_utf8_lossy(&raw[..end]);
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        if size == 0 {
+            return Some(body);
+        }
+
+        let chunk = raw.get(end + 2..end + 2 + size)?;
+        body.extend_from_slice(chunk);
+        raw = raw.get(end + 4 + size..)?;
+    }
+}
+
+/// The headers, session cookie included, go to curl on stdin rather than as
+/// arguments, which any local user could read.
+fn curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> Result<Response, Error> {
+    let failed =
+        |e: std::io::Error| Error::Http(format!("{} {}: running curl: {}", method, url, e));
+
+    let mut command = Command::new("curl");
+    command
+        .args(["--silent", "--show-error", "--request", method])
+        .args(["--user-agent", USER_AGENT])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--write-out", "\n%{http_code}"])
+        .args(["--header", "@-"]);
+    if let Some(body) = body {
+        command.args(["--data-raw", body]);
+    }
+
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(failed)?;
+    let mut stdin = child.stdin.take().unwrap();
+    let written = headers
+        .iter()
+        .try_for_each(|(name, value)| writeln!(stdin, "{}: {}", name, value));
+    drop(stdin);
+    if let Err(e) = written {
+        // Don't leave curl behind, it may still be waiting for its headers.
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(failed(e));
+    }
+    let output = child.wait_with_output().map_err(failed)?;
+    if !output.status.success() {
+        return Err(Error::Http(format!(
+            "{} {}: {}",
+            method,
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output
+        .rsplit_once('\n')
+        .and_then(|(body, status)| Some((body, status.trim().parse().ok()?)))
+        .ok_or_else(|| Error::Http(format!("{} {}: malformed curl output", method, url)))?;
+
+    Ok(Response {
+        status,
+        body: body.to_owned(),
+    })
+}
+
+/// A stand-in server for tests of the code talking to remote servers.
+#[cfg(test)]
+pub mod testing {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Answers one connection per response, in order, and returns the raw
+    /// requests it received once they have all been served.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Status\r\nContent-Length: {}\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (base_url, handle)
+    }
+
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut raw = vec![];
+        let mut buffer = [0; 1024];
+
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            raw.extend_from_slice(&buffer[..read]);
+
+            let text = String::from_utf8_lossy(&raw).into_owned();
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map_or(0, |length| length.parse().unwrap());
+                if body.len() >= length {
+                    return text;
+                }
+            }
+            if read == 0 {
+                return text;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_test() {
+        let (base_url, server) = testing::serve(vec![(200, "1000\n2000\n")]);
+        let response = get(
+            &format!("{}/2022/day/1/input", base_url),
+            &[("Cookie", "a=b")],
+        )
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "1000\n2000\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: a=b\r\n"));
+    }
+
+    #[test]
+    fn curl_test() {
+        let (base_url, server) = testing::serve(vec![(200, "ok")]);
+        let response = curl(
+            "POST",
+            &format!("{}/answer", base_url),
+            &[("Cookie", "session=secret")],
+            Some("level=1"),
+        )
+        .unwrap();
+
+        assert_eq!(response.body, "ok");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1"));
+    }
+
+    #[test]
+    fn address_test() {
+        assert_eq!(split_url("http://localhost").unwrap(), ("localhost", "/"));
+        assert_eq!(address("localhost"), "localhost:80");
+        assert_eq!(address("127.0.0.1:8080"), "127.0.0.1:8080");
+        assert_eq!(address("[::1]"), "[::1]:80");
+        assert_eq!(address("[::1]:8080"), "[::1]:8080");
+    }
+
+    #[test]
+    fn chunked_test() {
+        let raw = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nabcd\r\n2\r\nef\r\n0\r\n\r\n";
+
+        assert_eq!(
+            parse_response(raw),
+            Some(Response {
+                status: 200,
+                body: "abcdef".to_owned()
+            })
+        );
+    }
+}
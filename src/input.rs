@@ -0,0 +1,144 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+use crate::error::Error;
+
+/// Overrides the directory the default `dayN.txt` inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Passing this as a path reads the input from stdin instead.
+pub const STDIN: &str = "-";
+
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    input_dir().join(format!("day{}.txt", day))
+}
+
+/// How to refer to `path` in messages.
+pub fn name(path: &str) -> &str {
+    if path == STDIN {
+        "<stdin>"
+    } else {
+        path
+    }
+}
+
+/// Reads an input, normalised so that how it was saved doesn't matter.
+pub fn load(path: &str) -> Result<String, Error> {
+    let raw = if path == STDIN {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| Error::io(name(path), e))?;
+
+        input
+    } else {
+        fs::read_to_string(path).map_err(|e| Error::io(path, e))?
+    };
+
+    Ok(normalize(&raw))
+}
+
+/// Opens an input to be read bit by bit instead of loaded, for inputs too
+/// large to fit in memory. It isn't normalised.
+pub fn reader(path: &str) -> Result<Box<dyn BufRead>, Error> {
+    if path == STDIN {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = File::open(path).map_err(|e| Error::io(path, e))?;
+
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Drops a byte order mark, turns CRLF and lone CR line endings into LF, and
+/// ends any non-empty input with exactly one newline.
+pub fn normalize(raw: &str) -> String {
+    let text = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut text = text.replace("\r\n", "\n").replace('\r', "\n");
+
+    text.truncate(text.trim_end_matches('\n').len());
+    if !text.is_empty() {
+        text.push('\n');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{
+        registry::{self, Entry},
+        rng::Rng,
+        Part,
+    };
+
+    #[test]
+    fn load_test() {
+        assert_eq!(load("inputs/day2_test.txt").unwrap(), "A Y\nB X\nC Z\n");
+        assert!(load("inputs/missing.txt").is_err());
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(normalize("\u{feff}1\r\n\r\n2\r\n"), "1\n\n2\n");
+        assert_eq!(normalize("1\r2"), "1\n2\n");
+        assert_eq!(normalize("  1\n\n\n"), "  1\n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    fn answers(entry: &Entry, input: &str) -> Vec<String> {
+        match entry.solve(input, &Part::ALL) {
+            Ok(result) => result.parts.into_iter().map(|part| part.answer).collect(),
+            Err(error) => panic!("day {}: {}\n{:?}", entry.day, error, input),
+        }
+    }
+
+    #[test]
+    fn line_endings_test() {
+        let mut rng = Rng::new(2022);
+
+        for entry in registry::SOLUTIONS {
+            let example = format!("inputs/day{}_test.txt", entry.day);
+            let mut inputs = vec![];
+            if Path::new(&example).exists() {
+                inputs.push(load(&example).unwrap());
+            }
+            if let Some(oracle) = entry.oracle() {
+                inputs.push(normalize(&oracle.generate(&mut rng, 20)));
+            }
+
+            for input in inputs {
+                let expected = answers(entry, &input);
+                let crlf = input.replace('\n', "\r\n");
+                let variants = [
+                    format!("\u{feff}{}", crlf),
+                    format!("{}\r\n\r\n", crlf),
+                    format!("{}\n\n", input),
+                    input.trim_end_matches('\n').to_owned(),
+                ];
+
+                for variant in variants {
+                    assert_eq!(normalize(&variant), input);
+                    assert_eq!(answers(entry, &normalize(&variant)), expected);
+                }
+                // The days split lines themselves in a way that takes CRLF
+                // even without going through the loader.
+                assert_eq!(answers(entry, &crlf), expected, "day {}", entry.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::{self, Display, Write};
+
+/// Quotes and escapes `value` as a JSON string.
+pub fn string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+/// A flat JSON object, written out in insertion order.
+#[derive(Debug, Default)]
+pub struct Object {
+    fields: Vec<(String, String)>,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    pub fn number(self, key: &str, value: impl Display) -> Self {
+        self.raw(key, value.to_string())
+    }
+
+    pub fn string(self, key: &str, value: &str) -> Self {
+        self.raw(key, string(value))
+    }
+
+    /// Adds an already serialised JSON value.
+    pub fn raw(mut self, key: &str, json: String) -> Self {
+        self.fields.push((string(key), json));
+        self
+    }
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+
+        for (index, (key, value)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", key, value)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_test() {
+        assert_eq!(string("CMZ"), "\"CMZ\"");
+        assert_eq!(string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn object_test() {
+        let object = Object::new()
+            .number("day", 5)
+            .string("answer", "CMZ")
+            .raw("ok", "true".to_owned());
+
+        assert_eq!(
+            object.to_string(),
+            "{\"day\":5,\"answer\":\"CMZ\",\"ok\":true}"
+        );
+        assert_eq!(Object::new().to_string(), "{}");
+    }
+}
@@ -0,0 +1,127 @@
+use std::{fmt, fmt::Display, str::FromStr};
+
+use error::{Error, ParseError};
+
+pub mod answers;
+pub mod balance;
+pub mod bench;
+pub mod calendar;
+pub mod calories;
+pub mod cli;
+pub mod error;
+pub mod fetch;
+pub mod generator;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod memory;
+pub mod oracle;
+pub mod parallel;
+pub mod parser;
+pub mod puzzle;
+pub mod registry;
+pub mod rng;
+pub mod scaffold;
+pub mod submit;
+pub mod watch;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAlloc = memory::CountingAlloc;
+
+/// A single day of the calendar: how to parse the puzzle input and how to
+/// answer both of its parts.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Shared between the threads running the two parts.
+    type Input: Sync;
+    type Answer: AnswerValue;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer;
+
+    fn part2(input: &Self::Input) -> Self::Answer;
+}
+
+/// What a part can answer with: besides being printable, it knows its type
+/// name and how to write itself as JSON.
+pub trait AnswerValue: Display {
+    const TYPE: &'static str;
+
+    fn to_json(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl AnswerValue for u32 {
+    const TYPE: &'static str = "u32";
+}
+
+impl AnswerValue for u64 {
+    const TYPE: &'static str = "u64";
+}
+
+impl AnswerValue for usize {
+    const TYPE: &'static str = "usize";
+}
+
+impl AnswerValue for String {
+    const TYPE: &'static str = "String";
+
+    fn to_json(&self) -> String {
+        json::string(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::usage(format!(
+                "invalid part: {} (expected 1 or 2)",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Reads a file the tests rely on, panicking if it can't. Anything else loads
+/// inputs with `input::load`.
+#[cfg(test)]
+pub fn read_input(path: &str) -> String {
+    input::load(path).unwrap()
+}
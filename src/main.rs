@@ -1,3 +1,813 @@
-fn main() {
-    println!("Hello, world!");
+use std::{
+    env, fs, panic,
+    path::Path,
+    process::ExitCode,
+    str::FromStr,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc22::{
+    answers::{self, Answers},
+    balance, bench, calendar, calories,
+    cli::Args,
+    day1::{self, Day1},
+    error::Error,
+    fetch::Fetcher,
+    input, json, memory, oracle,
+    parallel::{self, Slots},
+    puzzle,
+    registry::{self, DayResult, Entry, Oracle},
+    rng::Rng,
+    scaffold,
+    submit::{self, Feedback, Submission, Submissions, Submitter, Warning},
+    watch, Part, Solution,
+};
+
+const USAGE: &str = "Usage:
+    aoc22 run <day> [--part 1|2] [--input PATH] [--format text|json] [--jobs N] [PATH...]
+    aoc22 run --all [--part 1|2] [--format text|json] [--jobs N]
+    aoc22 list
+    aoc22 bench <day|all> [--iterations N] [--warmup N] [--input PATH]
+    aoc22 mem <day|all> [--input PATH]
+    aoc22 gen <day> [--size N] [--seed S]
+    aoc22 check <day|all> [--cases N] [--seed S] [--max-size N]
+    aoc22 verify [--record] [--answers PATH]
+    aoc22 calendar [--readme PATH] [--answers PATH]
+    aoc22 new <day>
+    aoc22 fetch <day>
+    aoc22 submit <day> <part> [ANSWER] [--force] [--log PATH]
+    aoc22 watch <day> [--interval MS]
+    aoc22 calories top [--top K] [--input PATH] [--stream]
+    aoc22 calories report [--buckets N] [--most K] [--input PATH]
+    aoc22 calories balance [--elves N] [--exact-limit N] [--input PATH]
+    aoc22 example <day> <PAGE.html> [--block N] [--force] [--answers PATH]
+
+A PATH of `-` reads the input from stdin. Default inputs are read from
+`inputs/dayN.txt`, or from the directory in the AOC_INPUT_DIR variable.
+Missing default inputs are fetched with the session in AOC_SESSION (or
+~/.config/aoc22/session) from AOC_BASE_URL (https://adventofcode.com).";
+
+fn main() -> ExitCode {
+    let mut args = Args::new(env::args().skip(1));
+
+    let result = match args.positional().as_deref() {
+        Some("run") => run(args),
+        Some("list") => list(args),
+        Some("bench") => bench(args),
+        Some("mem") => mem(args),
+        Some("gen") => gen(args),
+        Some("check") => check(args),
+        Some("verify") => verify(args),
+        Some("calendar") => calendar(args),
+        Some("new") => new(args),
+        Some("example") => example(args),
+        Some("fetch") => fetch(args),
+        Some("submit") => submit(args),
+        Some("watch") => watch(args),
+        Some("calories") => calories(args),
+        Some("help") | None => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => Err(Error::usage(format!(
+            "unknown command: {}\n\n{}",
+            command, USAGE
+        ))),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error @ Error::Usage(_)) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(2)
+        }
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::usage(format!(
+                "invalid format: {} (expected text or json)",
+                s
+            ))),
+        }
+    }
+}
+
+fn run(mut args: Args) -> Result<(), Error> {
+    let all = args.flag("--all");
+    let parts = match args.value("--part")? {
+        Some(part) => vec![part.parse::<Part>()?],
+        None => Part::ALL.to_vec(),
+    };
+    let input_path = args.value("--input")?;
+    let format = match args.value("--format")? {
+        Some(format) => format.parse::<Format>()?,
+        None => Format::Text,
+    };
+    let jobs = parse_count(
+        args.value("--jobs")?,
+        thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+    )?;
+
+    let mut runs: Vec<(&Entry, String)> = vec![];
+    if all {
+        if input_path.is_some() {
+            return Err(Error::usage("--input cannot be combined with --all"));
+        }
+        args.finish()?;
+
+        for entry in registry::SOLUTIONS.iter() {
+            runs.push((entry, default_input(entry)?));
+        }
+    } else {
+        let day = args.positional().ok_or(Error::usage("missing day"))?;
+        let entry = parse_day(&day)?;
+
+        runs.extend(input_path.map(|path| (entry, path)));
+        while let Some(path) = args.positional() {
+            runs.push((entry, path));
+        }
+        args.finish()?;
+
+        if runs.is_empty() {
+            runs.push((entry, default_input(entry)?));
+        }
+    }
+
+    let start = Instant::now();
+    let results = parallel::map(&runs, jobs, |(entry, path), slots| {
+        solve_day(entry, path, &parts, slots)
+    });
+    let wall = start.elapsed();
+
+    let mut summed = Duration::ZERO;
+    let mut failed = 0;
+    for ((entry, path), results) in runs.iter().zip(results) {
+        if format == Format::Text {
+            if all {
+                println!("Day {}", entry.day);
+            } else if runs.len() > 1 {
+                println!("{}", path);
+            }
+        }
+
+        // A failed run doesn't keep the others from being reported.
+        let results = match results {
+            Ok(results) => results,
+            Err(error) if runs.len() > 1 => {
+                eprintln!("error: {}", error);
+                failed += 1;
+                continue;
+            }
+            Err(error) => return Err(error),
+        };
+        summed += results.parse_elapsed + results.parts.iter().map(|r| r.elapsed).sum::<Duration>();
+        print_results(entry, path, results, format)?;
+    }
+
+    if runs.len() > 1 {
+        eprintln!(
+            "{} runs on {} jobs: {} wall, {} summed",
+            runs.len(),
+            jobs,
+            bench::format_duration(wall),
+            bench::format_duration(summed)
+        );
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(Error::Mismatch(format!(
+            "{} of {} runs failed",
+            failed,
+            runs.len()
+        ))),
+    }
+}
+
+/// The day's real input, fetched first if it isn't there yet.
+fn default_input(entry: &Entry) -> Result<String, Error> {
+    Ok(Fetcher::from_env().ensure(entry.day)?.display().to_string())
+}
+
+fn solve_day(entry: &Entry, path: &str, parts: &[Part], slots: &Slots) -> Result<DayResult, Error> {
+    let input = input::load(path)?;
+
+    Ok(entry
+        .solve_concurrently(&input, parts, slots)
+        .map_err(|e| e.in_file(input::name(path)))?)
+}
+
+fn print_results(
+    entry: &Entry,
+    path: &str,
+    results: DayResult,
+    format: Format,
+) -> Result<(), Error> {
+    // Answers on the real input are checked against earlier submissions.
+    let submissions = if path == entry.default_input() {
+        Submissions::load(submit::SUBMISSIONS_PATH)?
+    } else {
+        Submissions::default()
+    };
+
+    for result in results.parts {
+        for warning in submissions.warnings(entry.day, result.part, &result.answer) {
+            if warning != Warning::Accepted {
+                eprintln!(
+                    "warning: part {}: {} {}",
+                    result.part, result.answer, warning
+                );
+            }
+        }
+
+        match format {
+            Format::Text => println!("Puzzle #{}: {}", result.part, result.answer),
+            Format::Json => println!(
+                "{}",
+                json::Object::new()
+                    .number("day", results.day)
+                    .number("part", result.part)
+                    .string("input", input::name(path))
+                    .raw("answer", result.answer_json)
+                    .string("answer_type", result.answer_type)
+                    .number("parse_ns", results.parse_elapsed.as_nanos())
+                    .number("solve_ns", result.elapsed.as_nanos())
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(mut args: Args) -> Result<(), Error> {
+    let iterations = parse_count(args.value("--iterations")?, 100)?;
+    let warmup = parse_count(args.value("--warmup")?, 10)?;
+    let input_path = args.value("--input")?;
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    let entries: Vec<&Entry> = if day == "all" {
+        if input_path.is_some() {
+            return Err(Error::usage("--input cannot be combined with all"));
+        }
+        registry::SOLUTIONS.iter().collect()
+    } else {
+        vec![parse_day(&day)?]
+    };
+
+    let mut benches = vec![];
+    for entry in entries {
+        let path = match &input_path {
+            Some(path) => path.clone(),
+            None => default_input(entry)?,
+        };
+        let input = input::load(&path)?;
+        let day_bench = bench::bench(entry, &input, warmup, iterations)
+            .map_err(|e| e.in_file(input::name(&path)))?;
+
+        benches.push(day_bench);
+    }
+
+    println!("{}", bench::table(&benches));
+
+    Ok(())
+}
+
+/// Needs the counting allocator of the `alloc-stats` feature.
+fn mem(mut args: Args) -> Result<(), Error> {
+    let input_path = args.value("--input")?;
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    if !memory::ENABLED {
+        return Err(Error::usage(
+            "allocation counting needs a build with `--features alloc-stats`",
+        ));
+    }
+
+    let entries: Vec<&Entry> = if day == "all" {
+        if input_path.is_some() {
+            return Err(Error::usage("--input cannot be combined with all"));
+        }
+        registry::SOLUTIONS.iter().collect()
+    } else {
+        vec![parse_day(&day)?]
+    };
+
+    let mut results = vec![];
+    for entry in entries {
+        let path = match &input_path {
+            Some(path) => path.clone(),
+            None => default_input(entry)?,
+        };
+        let input = input::load(&path)?;
+        let result = entry
+            .solve(&input, &Part::ALL)
+            .map_err(|e| e.in_file(input::name(&path)))?;
+
+        results.push(result);
+    }
+
+    println!("{}", memory::table(&results));
+
+    Ok(())
+}
+
+fn gen(mut args: Args) -> Result<(), Error> {
+    let size = parse_count(args.value("--size")?, 100)?;
+    let seed = parse_seed(args.value("--seed")?)?;
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    let oracle = parse_oracle(&day)?;
+    print!("{}", oracle.generate(&mut Rng::new(seed), size));
+
+    Ok(())
+}
+
+fn check(mut args: Args) -> Result<(), Error> {
+    let cases = parse_count(args.value("--cases")?, 1000)?;
+    let seed = parse_seed(args.value("--seed")?)?;
+    let max_size = parse_count(args.value("--max-size")?, 20)?;
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    let oracles: Vec<&Oracle> = if day == "all" {
+        registry::SOLUTIONS
+            .iter()
+            .filter_map(|entry| entry.oracle())
+            .collect()
+    } else {
+        vec![parse_oracle(&day)?]
+    };
+
+    // Panics of the solutions are reported as mismatches, not printed.
+    panic::set_hook(Box::new(|_| {}));
+
+    for oracle in oracles {
+        match oracle::differential(oracle, cases, seed, max_size) {
+            Ok(()) => println!("Day {}: {} cases OK", oracle.day, cases),
+            Err(mismatch) => {
+                return Err(Error::Mismatch(format!(
+                    "day {} part {}: expected {}, got {}\n\
+                     reproduce with `aoc22 gen {} --size {} --seed {}`, minimised input:\n{}",
+                    mismatch.day,
+                    mismatch.part,
+                    mismatch.expected,
+                    mismatch.actual,
+                    mismatch.day,
+                    mismatch.size,
+                    mismatch.seed,
+                    mismatch.input,
+                )))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(mut args: Args) -> Result<(), Error> {
+    let record = args.flag("--record");
+    let path = args
+        .value("--answers")?
+        .unwrap_or_else(|| answers::ANSWERS_PATH.to_owned());
+    args.finish()?;
+
+    let mut answers = Answers::load(&path)?;
+
+    if record {
+        let mut inputs: Vec<(u8, String)> = answers
+            .iter()
+            .map(|known| (known.day, known.input.clone()))
+            .collect();
+        for entry in registry::SOLUTIONS.iter() {
+            inputs.push((entry.day, entry.default_input()));
+            inputs.push((entry.day, format!("inputs/day{}_test.txt", entry.day)));
+        }
+        inputs.sort();
+        inputs.dedup();
+
+        for (day, input) in inputs {
+            if !Path::new(&input).exists() {
+                continue;
+            }
+
+            for part in Part::ALL {
+                let answer = answers::solve(day, part, &input)?;
+                println!("Day {} part {} {}: {}", day, part, input, answer);
+                answers.set(day, part, &input, &answer);
+            }
+        }
+
+        return answers.save(&path);
+    }
+
+    let checks = answers::verify(&answers);
+    if checks.is_empty() {
+        return Err(Error::usage(format!(
+            "no answers recorded in {}, run `aoc22 verify --record` first",
+            path
+        )));
+    }
+
+    for check in checks.iter() {
+        let status = match &check.actual {
+            Ok(_) if check.passed() => "ok".to_owned(),
+            Ok(actual) => format!("CHANGED (expected {}, got {})", check.expected, actual),
+            Err(error) => format!("FAILED\n{}", error),
+        };
+
+        println!(
+            "Day {} part {} {}: {}",
+            check.day, check.part, check.input, status
+        );
+    }
+
+    match checks.iter().filter(|check| !check.passed()).count() {
+        0 => Ok(()),
+        failed => Err(Error::Mismatch(format!(
+            "{} of {} answers changed",
+            failed,
+            checks.len()
+        ))),
+    }
+}
+
+fn calendar(mut args: Args) -> Result<(), Error> {
+    let readme_path = args
+        .value("--readme")?
+        .unwrap_or_else(|| "README.md".to_owned());
+    let answers_path = args
+        .value("--answers")?
+        .unwrap_or_else(|| answers::ANSWERS_PATH.to_owned());
+    args.finish()?;
+
+    let known = Answers::load(&answers_path)?;
+    let calendar = calendar::render(&calendar::build(&known));
+
+    let readme = fs::read_to_string(&readme_path).map_err(|e| Error::io(&readme_path, e))?;
+    let readme = calendar::rewrite(&readme, &calendar)?;
+    fs::write(&readme_path, readme).map_err(|e| Error::io(&readme_path, e))?;
+
+    print!("{}", calendar);
+
+    Ok(())
+}
+
+fn new(mut args: Args) -> Result<(), Error> {
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    let day = parse_day_number(&day)?;
+
+    for path in scaffold::scaffold(Path::new("."), day)? {
+        println!("wrote {}", path);
+    }
+    println!(
+        "paste the example into inputs/day{}_test.txt and its answers into the tests",
+        day
+    );
+
+    Ok(())
+}
+
+/// Takes the example input and answers out of a saved puzzle page.
+fn example(mut args: Args) -> Result<(), Error> {
+    let block = parse_count(args.value("--block")?, 1)?;
+    let force = args.flag("--force");
+    let answers_path = args
+        .value("--answers")?
+        .unwrap_or_else(|| answers::ANSWERS_PATH.to_owned());
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    let page = args.positional().ok_or(Error::usage("missing page"))?;
+    args.finish()?;
+
+    let day = parse_day_number(&day)?;
+    let html = fs::read_to_string(&page).map_err(|e| Error::io(&page, e))?;
+    let puzzle = puzzle::extract(&html);
+
+    let example = puzzle.examples.get(block - 1).ok_or_else(|| {
+        Error::usage(format!(
+            "{} has {} example blocks, not {}",
+            page,
+            puzzle.examples.len(),
+            block
+        ))
+    })?;
+
+    let test_path = format!("inputs/day{}_test.txt", day);
+    let existing = fs::read_to_string(&test_path).unwrap_or_default();
+    if !existing.is_empty() && !force {
+        return Err(Error::usage(format!(
+            "{} already exists, use --force to overwrite it",
+            test_path
+        )));
+    }
+    fs::write(&test_path, example).map_err(|e| Error::io(&test_path, e))?;
+    println!("wrote {}", test_path);
+
+    let mut known = Answers::load(&answers_path)?;
+    for (part, answer) in Part::ALL.into_iter().zip(puzzle.answers.iter()) {
+        match answer {
+            Some(answer) if !answer.is_empty() && !answer.contains(char::is_whitespace) => {
+                println!("Day {} part {} {}: {}", day, part, test_path, answer);
+                known.set(day, part, &test_path, answer);
+            }
+            Some(answer) => eprintln!("skipping part {} answer `{}`", part, answer),
+            None => eprintln!("no answer found for part {}", part),
+        }
+    }
+
+    known.save(&answers_path)
+}
+
+fn fetch(mut args: Args) -> Result<(), Error> {
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    let day = parse_day_number(&day)?;
+    let fetcher = Fetcher::from_env();
+
+    if fetcher.is_cached(day) {
+        println!("{} (cached)", fetcher.cache_path(day).display());
+    } else {
+        println!("{}", fetcher.ensure(day)?.display());
+    }
+
+    Ok(())
+}
+
+fn submit(mut args: Args) -> Result<(), Error> {
+    let force = args.flag("--force");
+    let log_path = args
+        .value("--log")?
+        .unwrap_or_else(|| submit::SUBMISSIONS_PATH.to_owned());
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    let part = args
+        .positional()
+        .ok_or(Error::usage("missing part"))?
+        .parse::<Part>()?;
+    let answer = args.positional();
+    args.finish()?;
+
+    let entry = parse_day(&day)?;
+    let input = default_input(entry)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => answers::solve(entry.day, part, &input)?,
+    };
+
+    let mut submissions = Submissions::load(&log_path)?;
+    let warnings = submissions.warnings(entry.day, part, &answer);
+    for warning in warnings.iter() {
+        eprintln!("warning: {} {}", answer, warning);
+    }
+    if !warnings.is_empty() && !force {
+        return Err(Error::usage(format!(
+            "not submitting {}, use --force to submit anyway",
+            answer
+        )));
+    }
+
+    let feedback = Submitter::from_env().submit(entry.day, part, &answer)?;
+    println!("Day {} part {} {}: {}", entry.day, part, answer, feedback);
+
+    submissions.record(Submission {
+        day: entry.day,
+        part,
+        feedback,
+        answer: answer.clone(),
+    });
+    submissions.save(&log_path)?;
+
+    // An accepted answer is a known good one from now on.
+    if feedback == Feedback::Correct {
+        let mut known = Answers::load(answers::ANSWERS_PATH)?;
+        known.set(entry.day, part, &input, &answer);
+        known.save(answers::ANSWERS_PATH)?;
+    }
+
+    Ok(())
+}
+
+/// Re-runs a day's tests and real input through cargo whenever its module
+/// or inputs change, until interrupted.
+fn watch(mut args: Args) -> Result<(), Error> {
+    let interval = Duration::from_millis(parse_count(args.value("--interval")?, 500)? as u64);
+    let day = args.positional().ok_or(Error::usage("missing day"))?;
+    args.finish()?;
+
+    // The day may be newer than this binary, so it only has to be valid.
+    let day = parse_day_number(&day)?;
+    let filter = format!("day{}::", day);
+    let mut watcher = watch::Watcher::new(watch::paths(Path::new("."), day));
+    let mut previous = vec![];
+
+    loop {
+        let tests = watch::cargo(&["test", "--quiet", "--lib", &filter])?;
+        if tests.status.success() {
+            println!("tests: ok");
+        } else {
+            println!("tests: FAILED");
+            print!("{}", String::from_utf8_lossy(&tests.stdout));
+            eprint!("{}", String::from_utf8_lossy(&tests.stderr));
+        }
+
+        let day = day.to_string();
+        let run = watch::cargo(&["run", "--quiet", "--release", "--", "run", &day])?;
+        eprint!("{}", String::from_utf8_lossy(&run.stderr));
+        if run.status.success() {
+            let current = watch::answers(&String::from_utf8_lossy(&run.stdout));
+            for line in watch::diff(&previous, &current) {
+                println!("{}", line);
+            }
+            previous = current;
+        }
+
+        let changed: Vec<String> = watcher
+            .wait(interval)
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        println!("\n{} changed", changed.join(", "));
+    }
+}
+
+/// Day 1 beyond the two puzzle answers.
+fn calories(mut args: Args) -> Result<(), Error> {
+    match args.positional().as_deref() {
+        Some("top") => calories_top(args),
+        Some("report") => calories_report(args),
+        Some("balance") => calories_balance(args),
+        Some(command) => Err(Error::usage(format!(
+            "unknown calories command: {}",
+            command
+        ))),
+        None => Err(Error::usage("missing calories command")),
+    }
+}
+
+/// The day 1 elves in `--input`, or in the real input.
+fn load_elves(path: Option<String>) -> Result<<Day1 as Solution>::Input, Error> {
+    let path = match path {
+        Some(path) => path,
+        None => default_input(parse_day("1")?)?,
+    };
+    let input = input::load(&path)?;
+
+    Ok(Day1::parse(&input).map_err(|e| e.in_file(input::name(&path)))?)
+}
+
+fn calories_top(mut args: Args) -> Result<(), Error> {
+    let k = parse_count(args.value("--top")?, 3)?;
+    let stream = args.flag("--stream");
+    let input_path = args.value("--input")?;
+    args.finish()?;
+
+    let top = if stream {
+        let path = match input_path {
+            Some(path) => path,
+            None => default_input(parse_day("1")?)?,
+        };
+        day1::top_k_reader(input::reader(&path)?, input::name(&path), k)?
+    } else {
+        day1::top_k(&load_elves(input_path)?, k)
+    };
+    for (rank, elf) in top.iter().enumerate() {
+        println!("#{} elf {}: {}", rank + 1, elf.index + 1, elf.total);
+    }
+    println!("Total: {}", top.iter().map(|elf| elf.total).sum::<u64>());
+
+    Ok(())
+}
+
+fn calories_report(mut args: Args) -> Result<(), Error> {
+    let buckets = parse_count(args.value("--buckets")?, 10)?;
+    let most = parse_count(args.value("--most")?, 3)?;
+    let input_path = args.value("--input")?;
+    args.finish()?;
+
+    let elves = load_elves(input_path)?;
+    match calories::report(&elves, buckets, most) {
+        Some(report) => println!("{}", report),
+        None => println!("no elves"),
+    }
+
+    Ok(())
+}
+
+/// Redistributes all the items to lighten the heaviest load, over as many
+/// elves as the input has unless `--elves` says otherwise.
+fn calories_balance(mut args: Args) -> Result<(), Error> {
+    let elves = args.value("--elves")?;
+    let exact_limit = parse_count(args.value("--exact-limit")?, balance::EXACT_LIMIT)?;
+    let input_path = args.value("--input")?;
+    args.finish()?;
+
+    // Checked before anything is loaded; without `--elves` there are only
+    // as many as the input has.
+    let elves = match elves {
+        Some(elves) => Some(parse_count(Some(elves), 1)?),
+        None => None,
+    };
+    if let Some(elves) = elves.filter(|&elves| elves > balance::MAX_ELVES) {
+        return Err(Error::usage(format!(
+            "too many elves: {}, at most {}",
+            elves,
+            balance::MAX_ELVES
+        )));
+    }
+
+    let inventory = load_elves(input_path)?;
+    let elves = elves.unwrap_or(inventory.len().max(1));
+    let items: Vec<u64> = inventory
+        .iter()
+        .flat_map(|elf| elf.items.iter().copied())
+        .collect();
+
+    println!("{}", balance::balance(&items, elves, exact_limit));
+
+    Ok(())
+}
+
+fn list(args: Args) -> Result<(), Error> {
+    args.finish()?;
+
+    for entry in registry::SOLUTIONS.iter() {
+        println!("Day {} ({})", entry.day, entry.default_input());
+    }
+
+    Ok(())
+}
+
+fn parse_count(value: Option<String>, default: usize) -> Result<usize, Error> {
+    match value {
+        Some(value) => match value.parse::<usize>() {
+            Ok(count) if count > 0 => Ok(count),
+            _ => Err(Error::usage(format!("invalid count: {}", value))),
+        },
+        None => Ok(default),
+    }
+}
+
+/// Without an explicit seed, a time based one is used and printed.
+fn parse_seed(value: Option<String>) -> Result<u64, Error> {
+    match value {
+        Some(seed) => seed
+            .parse::<u64>()
+            .map_err(|_| Error::usage(format!("invalid seed: {}", seed))),
+        None => {
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_nanos() as u64)
+                .unwrap_or_default();
+            eprintln!("seed: {}", seed);
+
+            Ok(seed)
+        }
+    }
+}
+
+fn parse_day_number(day: &str) -> Result<u8, Error> {
+    match day.parse::<u8>() {
+        Ok(number) if (1..=calendar::DAYS).contains(&number) => Ok(number),
+        _ => Err(Error::usage(format!("invalid day: {}", day))),
+    }
+}
+
+fn parse_day(day: &str) -> Result<&'static Entry, Error> {
+    day.parse::<u8>()
+        .ok()
+        .and_then(registry::find)
+        .ok_or_else(|| Error::usage(format!("no solution registered for day {}", day)))
+}
+
+fn parse_oracle(day: &str) -> Result<&'static Oracle, Error> {
+    let entry = parse_day(day)?;
+
+    entry.oracle().ok_or_else(|| {
+        Error::usage(format!(
+            "day {} has no input generator or reference solution",
+            entry.day
+        ))
+    })
 }
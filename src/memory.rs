@@ -0,0 +1,205 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use crate::{bench::PHASES, registry::DayResult};
+
+/// Whether the counting allocator is installed, which needs the
+/// `alloc-stats` feature. Without it every measurement comes out as zero.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// The system allocator, counting what each thread allocates so that the
+/// phases of a day can be measured even while other days run in parallel.
+pub struct CountingAlloc;
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    /// Memory freed by another thread than the one that allocated it can
+    /// make this negative.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    // Fails only while the thread is being torn down.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+        f(&mut current);
+        counters.set(current);
+    });
+}
+
+fn allocated(size: usize) {
+    update(|counters| {
+        counters.allocations += 1;
+        counters.bytes += size as u64;
+        counters.live += size as i64;
+        counters.peak = counters.peak.max(counters.live);
+    });
+}
+
+fn freed(size: usize) {
+    update(|counters| counters.live -= size as i64);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Allocations and reallocations.
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes live at once, above what was live beforehand.
+    pub peak: u64,
+}
+
+/// Runs `f` and measures what it allocates on the current thread.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, AllocStats) {
+    let mut before = None;
+    update(|counters| {
+        counters.peak = counters.live;
+        before = Some(*counters);
+    });
+
+    let result = f();
+
+    let mut stats = AllocStats::default();
+    update(|after| {
+        if let Some(before) = before {
+            stats = AllocStats {
+                allocations: after.allocations - before.allocations,
+                bytes: after.bytes - before.bytes,
+                peak: (after.peak - before.live).max(0) as u64,
+            };
+        }
+    });
+
+    (result, stats)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.2} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.2} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+pub fn table(results: &[DayResult]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<6} {:>11} {:>11} {:>11}\n",
+        "Day", "Phase", "Allocs", "Bytes", "Peak"
+    );
+
+    for result in results.iter() {
+        let phases = [result.parse_allocs]
+            .into_iter()
+            .chain(result.parts.iter().map(|part| part.allocs));
+
+        for (phase, stats) in PHASES.iter().zip(phases) {
+            table += &format!(
+                "{:>3}  {:<6} {:>11} {:>11} {:>11}\n",
+                result.day,
+                phase,
+                stats.allocations,
+                format_bytes(stats.bytes),
+                format_bytes(stats.peak),
+            );
+        }
+    }
+
+    let total: u64 = results
+        .iter()
+        .map(|result| {
+            result.parse_allocs.bytes
+                + result
+                    .parts
+                    .iter()
+                    .map(|part| part.allocs.bytes)
+                    .sum::<u64>()
+        })
+        .sum();
+    table += &format!("Total allocated: {}", format_bytes(total));
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_test() {
+        let (sum, stats) = measure(|| {
+            let first = vec![1u64; 1000];
+            let second = vec![2u64; 1000];
+            drop(first);
+            let third = vec![3u64; 500];
+
+            second.iter().chain(third.iter()).sum::<u64>()
+        });
+
+        assert_eq!(sum, 3500);
+        if ENABLED {
+            assert_eq!(stats.allocations, 3);
+            assert_eq!(stats.bytes, 20_000);
+            assert_eq!(stats.peak, 16_000);
+        } else {
+            assert_eq!(stats, AllocStats::default());
+        }
+    }
+
+    #[test]
+    fn format_bytes_test() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(2048), "2.00 KiB");
+        assert_eq!(format_bytes(3 * 1_048_576), "3.00 MiB");
+    }
+}
@@ -0,0 +1,197 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{registry::Oracle, rng::Rng, Part, Solution};
+
+/// Deliberately naive implementations of a day, written for obviousness
+/// rather than speed. They work on the raw input and may assume it is valid.
+pub trait Reference: Solution {
+    fn reference_part1(input: &str) -> Self::Answer;
+
+    fn reference_part2(input: &str) -> Self::Answer;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The input was rejected by the parser or broke the reference.
+    Invalid,
+    Match,
+    Mismatch {
+        part: Part,
+        expected: String,
+        actual: String,
+    },
+}
+
+pub fn compare<S: Reference>(input: &str) -> Verdict {
+    let parsed = match panic::catch_unwind(|| S::parse(input)) {
+        Ok(Ok(parsed)) => parsed,
+        _ => return Verdict::Invalid,
+    };
+
+    for part in Part::ALL {
+        let expected = panic::catch_unwind(|| match part {
+            Part::One => S::reference_part1(input).to_string(),
+            Part::Two => S::reference_part2(input).to_string(),
+        });
+        let expected = match expected {
+            Ok(expected) => expected,
+            Err(_) => return Verdict::Invalid,
+        };
+
+        let actual = panic::catch_unwind(AssertUnwindSafe(|| match part {
+            Part::One => S::part1(&parsed).to_string(),
+            Part::Two => S::part2(&parsed).to_string(),
+        }))
+        .unwrap_or_else(|_| "<panicked>".to_owned());
+
+        if actual != expected {
+            return Verdict::Mismatch {
+                part,
+                expected,
+                actual,
+            };
+        }
+    }
+
+    Verdict::Match
+}
+
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+    /// The smallest input found that still shows the mismatch.
+    pub input: String,
+}
+
+/// Runs `cases` generated inputs of up to `max_size` through both the fast
+/// and the reference solutions. Case `n` uses seed `seed + n`, so a reported
+/// mismatch can be reproduced with `aoc22 gen`.
+pub fn differential(
+    oracle: &Oracle,
+    cases: usize,
+    seed: u64,
+    max_size: usize,
+) -> Result<(), Mismatch> {
+    for case in 0..cases as u64 {
+        let case_seed = seed.wrapping_add(case);
+        let mut rng = Rng::new(case_seed);
+        let size = 1 + rng.below(max_size.max(1));
+        let input = oracle.generate(&mut Rng::new(case_seed), size);
+
+        if let Verdict::Mismatch { part, .. } = oracle.compare(&input) {
+            let input = minimise(
+                &input,
+                |candidate| matches!(oracle.compare(candidate), Verdict::Mismatch { part: p, .. } if p == part),
+            );
+
+            if let Verdict::Mismatch {
+                part,
+                expected,
+                actual,
+            } = oracle.compare(&input)
+            {
+                return Err(Mismatch {
+                    day: oracle.day,
+                    seed: case_seed,
+                    size,
+                    part,
+                    expected,
+                    actual,
+                    input,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Greedily drops chunks of lines (or of characters, for single line inputs)
+/// for as long as `fails` keeps holding.
+pub fn minimise(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let trailing_newline = if input.ends_with('\n') { "\n" } else { "" };
+    let single_line = input.lines().count() <= 1;
+
+    let mut units: Vec<String> = if single_line {
+        input
+            .trim_end_matches('\n')
+            .chars()
+            .map(String::from)
+            .collect()
+    } else {
+        input.lines().map(String::from).collect()
+    };
+    let join = |units: &[String]| {
+        let separator = if single_line { "" } else { "\n" };
+
+        units.join(separator) + trailing_newline
+    };
+
+    let mut chunk = units.len() / 2;
+
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate: Vec<String> = units[..start]
+                .iter()
+                .chain(units[end..].iter())
+                .cloned()
+                .collect();
+
+            if fails(&join(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    join(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimise_lines_test() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let minimised = minimise(input, |candidate| {
+            candidate.contains("3") && candidate.contains("6")
+        });
+
+        assert_eq!(minimised, "3\n6\n");
+    }
+
+    #[test]
+    fn minimise_chars_test() {
+        let minimised = minimise("abcxdefxg", |candidate| candidate.matches('x').count() == 2);
+
+        assert_eq!(minimised, "xx");
+    }
+
+    #[test]
+    fn differential_test() {
+        for oracle in crate::registry::SOLUTIONS
+            .iter()
+            .filter_map(|entry| entry.oracle())
+        {
+            if let Err(mismatch) = differential(oracle, 100, 0, 20) {
+                panic!("{:?}", mismatch);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+/// Spare threads under a `--jobs` cap. Workers of `map` hold a slot for as
+/// long as they run; the work they do can borrow a free one for a helper
+/// thread.
+pub struct Slots {
+    free: Mutex<usize>,
+}
+
+impl Slots {
+    pub fn new(free: usize) -> Self {
+        Slots {
+            free: Mutex::new(free),
+        }
+    }
+
+    /// No spare threads: everything runs on the calling one.
+    pub fn none() -> Self {
+        Slots::new(0)
+    }
+
+    pub fn try_take(&self) -> bool {
+        let mut free = self.free.lock().unwrap();
+
+        if *free > 0 {
+            *free -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn give_back(&self) {
+        *self.free.lock().unwrap() += 1;
+    }
+}
+
+/// Maps `f` over `items` on up to `jobs` threads, returning the results in
+/// the order of `items`. `f` is handed the slots the workers leave free. A
+/// panic in `f` carries on on the calling thread.
+pub fn map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T, &Slots) -> R + Sync,
+) -> Vec<R> {
+    let workers = jobs.clamp(1, items.len().max(1));
+    let slots = Slots::new(jobs.saturating_sub(workers));
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+
+                        let result = f(item, &slots);
+                        results.lock().unwrap()[index] = Some(result);
+                    }
+
+                    // A finished worker's thread can go to the remaining work.
+                    slots.give_back();
+                })
+            })
+            .collect();
+
+        // Joining the workers here rather than leaving it to the scope keeps
+        // the original panic instead of a generic one.
+        for handle in handles {
+            if let Err(payload) = handle.join() {
+                panic::resume_unwind(payload);
+            }
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::atomic::AtomicUsize, thread, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn map_keeps_order_test() {
+        let items: Vec<u64> = (0..20).collect();
+        let squares = map(&items, 4, |&n, _| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+
+        assert_eq!(squares, items.iter().map(|n| n * n).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn panic_test() {
+        let payload = panic::catch_unwind(|| {
+            map(&[1, 2, 3], 2, |&n, _| {
+                if n == 2 {
+                    panic!("bad item {}", n);
+                }
+                n
+            })
+        })
+        .unwrap_err();
+
+        assert_eq!(payload.downcast_ref::<String>().unwrap(), "bad item 2");
+    }
+
+    #[test]
+    fn jobs_cap_test() {
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        let work = || {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            running.fetch_sub(1, Ordering::SeqCst);
+        };
+
+        map(&[(); 8], 3, |_, slots| {
+            if slots.try_take() {
+                thread::scope(|scope| {
+                    scope.spawn(work);
+                    work();
+                });
+                slots.give_back();
+            } else {
+                work();
+            }
+        });
+
+        assert!(most.load(Ordering::SeqCst) <= 3);
+    }
+}
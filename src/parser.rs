@@ -0,0 +1,350 @@
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// The unparsed rest of a line, remembering the whole line and its number
+/// so errors can point at the right column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    line: usize,
+    text: &'a str,
+    offset: usize,
+}
+
+/// What a parser returns: the value and the span after it.
+pub type PResult<'a, T> = Result<(T, Span<'a>), ParseError>;
+
+impl<'a> Span<'a> {
+    pub fn new(line: usize, text: &'a str) -> Self {
+        Span {
+            line,
+            text,
+            offset: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.offset..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    fn advance(self, bytes: usize) -> Self {
+        Span {
+            offset: self.offset + bytes,
+            ..self
+        }
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.line, self.text, self.rest(), message)
+    }
+
+    /// An error at `token`, which has to be a slice of this line.
+    pub fn error_at(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.line, self.text, token, message)
+    }
+}
+
+/// The exact text `expected`.
+pub fn tag<'a>(expected: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    move |span| match span.rest().strip_prefix(expected) {
+        Some(_) => Ok((&span.rest()[..expected.len()], span.advance(expected.len()))),
+        None => Err(span.error(format!("expected `{}`", expected))),
+    }
+}
+
+/// The longest run of characters matching `pred`, possibly empty.
+pub fn take_while<'a>(pred: impl Fn(char) -> bool) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    move |span| {
+        let rest = span.rest();
+        let end = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+
+        Ok((&rest[..end], span.advance(end)))
+    }
+}
+
+/// Like `take_while`, but a missing `what` is an error.
+pub fn take_while1<'a>(
+    what: &'static str,
+    pred: impl Fn(char) -> bool,
+) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    let take = take_while(pred);
+
+    move |span| match take(span)? {
+        ("", _) => Err(span.error(format!("missing {}", what))),
+        taken => Ok(taken),
+    }
+}
+
+pub fn space0<'a>(span: Span<'a>) -> PResult<'a, &'a str> {
+    take_while(|c| c == ' ' || c == '\t')(span)
+}
+
+pub fn space1<'a>(span: Span<'a>) -> PResult<'a, &'a str> {
+    take_while1("space", |c| c == ' ' || c == '\t')(span)
+}
+
+/// A run of anything but whitespace, after optional spaces.
+pub fn word<'a>(what: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, &'a str> {
+    preceded(space0, take_while1(what, |c| !c.is_whitespace()))
+}
+
+/// An unsigned decimal number. The error for a bad one points at the whole
+/// alphanumeric token, e.g. `12a`.
+pub fn integer<'a, T: FromStr>(what: &'static str) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    let token = take_while1(what, |c| c.is_ascii_alphanumeric());
+
+    move |span| {
+        let (token, rest) = token(span)?;
+
+        match token.parse() {
+            Ok(number) if token.bytes().all(|b| b.is_ascii_digit()) => Ok((number, rest)),
+            _ => Err(span.error_at(token, format!("invalid {} `{}`", what, token))),
+        }
+    }
+}
+
+/// Checks or converts what `parser` returns, reporting `f`'s error at the
+/// text it consumed.
+pub fn map_res<'a, T, U>(
+    parser: impl Fn(Span<'a>) -> PResult<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Fn(Span<'a>) -> PResult<'a, U> {
+    move |span| {
+        let (value, rest) = parser(span)?;
+        let consumed = &span.rest()[..rest.offset - span.offset];
+
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(message) => Err(span.error_at(consumed.trim_start(), message)),
+        }
+    }
+}
+
+pub fn map<'a, T, U>(
+    parser: impl Fn(Span<'a>) -> PResult<'a, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(Span<'a>) -> PResult<'a, U> {
+    move |span| parser(span).map(|(value, rest)| (f(value), rest))
+}
+
+/// `second`, after `first`.
+pub fn preceded<'a, T, U>(
+    first: impl Fn(Span<'a>) -> PResult<'a, T>,
+    second: impl Fn(Span<'a>) -> PResult<'a, U>,
+) -> impl Fn(Span<'a>) -> PResult<'a, U> {
+    move |span| second(first(span)?.1)
+}
+
+/// `first`, followed by `second`.
+pub fn terminated<'a, T, U>(
+    first: impl Fn(Span<'a>) -> PResult<'a, T>,
+    second: impl Fn(Span<'a>) -> PResult<'a, U>,
+) -> impl Fn(Span<'a>) -> PResult<'a, T> {
+    move |span| {
+        let (value, rest) = first(span)?;
+
+        Ok((value, second(rest)?.1))
+    }
+}
+
+/// One or more `item`s with a `separator` between each.
+pub fn separated<'a, T, S>(
+    item: impl Fn(Span<'a>) -> PResult<'a, T>,
+    separator: impl Fn(Span<'a>) -> PResult<'a, S>,
+) -> impl Fn(Span<'a>) -> PResult<'a, Vec<T>> {
+    move |span| {
+        let (first, mut span) = item(span)?;
+        let mut items = vec![first];
+
+        while let Ok((_, rest)) = separator(span) {
+            let (next, rest) = item(rest)?;
+            items.push(next);
+            span = rest;
+        }
+
+        Ok((items, span))
+    }
+}
+
+/// Nothing but spaces left on the line.
+pub fn end(span: Span) -> PResult<()> {
+    let (_, span) = space0(span)?;
+
+    match span.rest().split_whitespace().next() {
+        Some(extra) => Err(span.error_at(extra, format!("unexpected `{}`", extra))),
+        None => Ok(((), span)),
+    }
+}
+
+/// Parses a whole line with `parser`.
+pub fn line<'a, T>(
+    line: usize,
+    text: &'a str,
+    parser: impl FnOnce(Span<'a>) -> PResult<'a, T>,
+) -> Result<T, ParseError> {
+    let (value, rest) = parser(Span::new(line, text))?;
+    end(rest)?;
+
+    Ok(value)
+}
+
+/// Parses every line of `input` with `parser`.
+pub fn lines<'a, T>(
+    input: &'a str,
+    parser: impl Fn(Span<'a>) -> PResult<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| line(index + 1, text, &parser))
+        .collect()
+}
+
+/// A group of lines without blank lines in between.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The number of the block's first line in the input.
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    /// The lines with their numbers in the input.
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, &text)| (self.first_line + index, text))
+    }
+
+    pub fn parse_lines<T>(
+        &self,
+        parser: impl Fn(Span<'a>) -> PResult<'a, T>,
+    ) -> Result<Vec<T>, ParseError> {
+        self.numbered()
+            .map(|(number, text)| line(number, text, &parser))
+            .collect()
+    }
+}
+
+/// Splits `input` at every empty line, like `str::split` would: two blank
+/// lines in a row give an empty block, and a blank last line gives an empty
+/// last block.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![Block {
+        first_line: 1,
+        lines: vec![],
+    }];
+
+    for (index, text) in input.lines().enumerate() {
+        if text.is_empty() {
+            blocks.push(Block {
+                first_line: index + 2,
+                lines: vec![],
+            });
+        } else {
+            blocks.last_mut().unwrap().lines.push(text);
+        }
+    }
+
+    blocks
+}
+
+/// The characters of `count` fixed-width columns, the first at character
+/// `offset` and each `stride` further, with their 1-based column numbers.
+/// Columns past the end of a short line are `None`.
+pub fn cells(text: &str, count: usize, offset: usize, stride: usize) -> Vec<(usize, Option<char>)> {
+    let chars: Vec<char> = text.chars().collect();
+
+    (0..count)
+        .map(|index| {
+            let column = offset + index * stride;
+
+            (column + 1, chars.get(column).copied())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_test() {
+        let section = integer::<u16>("section");
+
+        assert_eq!(section(Span::new(1, "42-7")).unwrap().0, 42);
+
+        let error = preceded(tag("2-"), &section)(Span::new(3, "2-x,4")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.message, "invalid section `x`");
+
+        let error = section(Span::new(1, "")).unwrap_err();
+        assert_eq!(error.message, "missing section");
+    }
+
+    #[test]
+    fn separated_test() {
+        let numbers = separated(integer::<u32>("number"), tag(","));
+
+        assert_eq!(line(1, "1,22,333", &numbers).unwrap(), vec![1, 22, 333]);
+
+        let error = line(1, "1,22 x", &numbers).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (6, "unexpected `x`")
+        );
+        assert!(line(1, "1,", &numbers).is_err());
+    }
+
+    #[test]
+    fn map_res_test() {
+        let stack = map_res(preceded(space0, integer::<usize>("stack")), |stack| {
+            if stack <= 3 {
+                Ok(stack)
+            } else {
+                Err(format!("no stack {}", stack))
+            }
+        });
+
+        assert_eq!(line(1, " 3", &stack).unwrap(), 3);
+        assert_eq!(
+            line(1, "to 7", preceded(tag("to"), &stack))
+                .unwrap_err()
+                .column,
+            4
+        );
+    }
+
+    #[test]
+    fn blocks_test() {
+        let blocks = blocks("1\n2\n\n3\n\n\n4\n");
+
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(blocks[0].lines, vec!["1", "2"]);
+        assert_eq!(blocks[1].numbered().collect::<Vec<_>>(), vec![(4, "3")]);
+        assert!(blocks[2].lines.is_empty());
+        assert_eq!(blocks[3].first_line, 7);
+    }
+
+    #[test]
+    fn cells_test() {
+        assert_eq!(
+            cells("[A]     [C]", 4, 1, 4),
+            vec![(2, Some('A')), (6, Some(' ')), (10, Some('C')), (14, None)]
+        );
+    }
+}
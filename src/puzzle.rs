@@ -0,0 +1,157 @@
+/// What a saved puzzle page says about its examples: the `<pre><code>`
+/// blocks and, per part, the emphasised answer the example leads to.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub examples: Vec<String>,
+    /// One entry per part description on the page, in order.
+    pub answers: Vec<Option<String>>,
+}
+
+/// Extracts the examples and example answers from the HTML of a puzzle page.
+/// Each part is an `<article>`, and its example answer is taken to be the last
+/// `<code><em>` in it, which is where the puzzle text states the result.
+pub fn extract(html: &str) -> Puzzle {
+    let mut puzzle = Puzzle::default();
+
+    for article in elements(html, "<article", "</article>") {
+        puzzle.examples.extend(
+            elements(article, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .map(text),
+        );
+        puzzle.answers.push(
+            elements(article, "<code><em>", "</em></code>")
+                .last()
+                .map(|&answer| text(answer)),
+        );
+    }
+
+    puzzle
+}
+
+/// The contents of every `open ... close` element, where `open` may be the
+/// start of a tag with attributes.
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        if !open.ends_with('>') {
+            match rest.find('>') {
+                Some(end) => rest = &rest[end + 1..],
+                None => break,
+            }
+        }
+
+        match rest.find(close) {
+            Some(end) => {
+                found.push(&rest[..end]);
+                rest = &rest[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+
+    found
+}
+
+/// The text of an HTML fragment: tags dropped and entities decoded.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => match rest.find('>') {
+                Some(end) => rest = &rest[end + 1..],
+                None => break,
+            },
+            '&' => match rest
+                .find(';')
+                .and_then(|end| Some((entity(&rest[1..end])?, end)))
+            {
+                Some((decoded, end)) => {
+                    text.push(decoded);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    text.push('&');
+                    rest = &rest[1..];
+                }
+            },
+            _ => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    text
+}
+
+fn entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+
+            char::from_u32(code)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 4: Camp Cleanup ---</h2>
+<p>For example, consider the following list:</p>
+<pre><code>2-4,6-8
+<em>2-8</em>,3-7
+</code></pre>
+<p>Some pairs use <code>&lt;</code> &amp; <code>&#62;</code>.</p>
+<p>In how many pairs? In this example, there are <code><em>2</em></code> such pairs.</p>
+</article>
+<p>Your puzzle answer was <code>485</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In this example, the number of overlapping pairs is <code><em>4</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn extract_test() {
+        let puzzle = extract(PAGE);
+
+        assert_eq!(puzzle.examples, vec!["2-4,6-8\n2-8,3-7\n"]);
+        assert_eq!(
+            puzzle.answers,
+            vec![Some("2".to_owned()), Some("4".to_owned())]
+        );
+    }
+
+    #[test]
+    fn text_test() {
+        assert_eq!(
+            text("<em>a</em> &lt;&#x41;&#66;&gt; &amp;c &bogus;"),
+            "a <AB> &c &bogus;"
+        );
+    }
+
+    #[test]
+    fn unsolved_page_test() {
+        let puzzle = extract("<article><p>No example here.</p></article>");
+
+        assert_eq!(puzzle.examples, Vec::<String>::new());
+        assert_eq!(puzzle.answers, vec![None]);
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    panic, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    day1::Day1,
+    day2::Day2,
+    day3::Day3,
+    day4::Day4,
+    day5::Day5,
+    day6::Day6,
+    day7::Day7,
+    day8::Day8,
+    error::ParseError,
+    generator::Generator,
+    input,
+    memory::{self, AllocStats},
+    oracle::{self, Reference, Verdict},
+    parallel::Slots,
+    rng::Rng,
+    AnswerValue, Part, Solution,
+};
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    pub answer_type: &'static str,
+    pub answer_json: String,
+    pub elapsed: Duration,
+    pub allocs: AllocStats,
+}
+
+pub struct DayResult {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parse_allocs: AllocStats,
+    pub parts: Vec<PartResult>,
+}
+
+/// A type-erased registered day, so the runner can dispatch on a day number.
+pub struct Entry {
+    pub day: u8,
+    solve: fn(&str, &[Part], &Slots) -> Result<DayResult, ParseError>,
+    oracle: Option<Oracle>,
+}
+
+/// The input generator and reference solution of a day, for the days that
+/// have them.
+pub struct Oracle {
+    pub day: u8,
+    generate: fn(&mut Rng, usize) -> String,
+    compare: fn(&str) -> Verdict,
+}
+
+impl Entry {
+    /// A day with only a solution, as created by `aoc22 new`.
+    pub const fn new<S: Solution>() -> Self {
+        Entry {
+            day: S::DAY,
+            solve: solve::<S>,
+            oracle: None,
+        }
+    }
+
+    /// A day that can also be differentially checked.
+    pub const fn checked<S: Solution + Generator + Reference>() -> Self {
+        Entry {
+            day: S::DAY,
+            solve: solve::<S>,
+            oracle: Some(Oracle {
+                day: S::DAY,
+                generate: S::generate,
+                compare: oracle::compare::<S>,
+            }),
+        }
+    }
+
+    pub fn default_input(&self) -> String {
+        input::default_path(self.day).display().to_string()
+    }
+
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+        (self.solve)(input, parts, &Slots::none())
+    }
+
+    /// Like `solve`, but runs the parts at the same time when one of `slots`
+    /// is free.
+    pub fn solve_concurrently(
+        &self,
+        input: &str,
+        parts: &[Part],
+        slots: &Slots,
+    ) -> Result<DayResult, ParseError> {
+        (self.solve)(input, parts, slots)
+    }
+
+    pub fn oracle(&self) -> Option<&Oracle> {
+        self.oracle.as_ref()
+    }
+}
+
+impl Oracle {
+    pub fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (self.generate)(rng, size)
+    }
+
+    /// Checks the fast solution against the reference one on `input`.
+    pub fn compare(&self, input: &str) -> Verdict {
+        (self.compare)(input)
+    }
+}
+
+pub static SOLUTIONS: &[Entry] = &[
+    Entry::checked::<Day1>(),
+    Entry::checked::<Day2>(),
+    Entry::checked::<Day3>(),
+    Entry::checked::<Day4>(),
+    Entry::checked::<Day5>(),
+    Entry::checked::<Day6>(),
+    Entry::checked::<Day7>(),
+    Entry::checked::<Day8>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Entry> {
+    SOLUTIONS.iter().find(|entry| entry.day == day)
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part], slots: &Slots) -> Result<DayResult, ParseError> {
+    let start = Instant::now();
+    let (input, parse_allocs) = memory::measure(|| S::parse(input));
+    let input = input?;
+    let parse_elapsed = start.elapsed();
+
+    let solve_part = |part: Part| {
+        let start = Instant::now();
+        let (answer, allocs) = memory::measure(|| match part {
+            Part::One => S::part1(&input),
+            Part::Two => S::part2(&input),
+        });
+        let elapsed = start.elapsed();
+
+        PartResult {
+            part,
+            answer: answer.to_string(),
+            answer_type: S::Answer::TYPE,
+            answer_json: answer.to_json(),
+            elapsed,
+            allocs,
+        }
+    };
+
+    let parts = match parts {
+        &[first, second] if slots.try_take() => {
+            let results = thread::scope(|scope| {
+                let second = scope.spawn(|| solve_part(second));
+                let first = solve_part(first);
+
+                let second = second
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+                vec![first, second]
+            });
+            slots.give_back();
+
+            results
+        }
+        _ => parts.iter().map(|&part| solve_part(part)).collect(),
+    };
+
+    Ok(DayResult {
+        day: S::DAY,
+        parse_elapsed,
+        parse_allocs,
+        parts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days: Vec<u8> = SOLUTIONS.iter().map(|entry| entry.day).collect();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(days.iter().all(|day| (1..=25).contains(day)));
+    }
+
+    #[test]
+    fn solve_test() {
+        let input = read_input("inputs/day5_test.txt");
+        let results = find(5).unwrap().solve(&input, &Part::ALL).unwrap();
+
+        assert_eq!(results.day, 5);
+        assert_eq!(results.parts[0].answer, "CMZ");
+        assert_eq!(results.parts[1].answer, "MCD");
+    }
+
+    #[test]
+    fn solve_concurrently_test() {
+        let input = read_input("inputs/day7_test.txt");
+        let slots = Slots::new(1);
+        let results = find(7)
+            .unwrap()
+            .solve_concurrently(&input, &Part::ALL, &slots)
+            .unwrap();
+
+        assert_eq!(results.parts[0].part, Part::One);
+        assert_eq!(results.parts[0].answer, "95437");
+        assert_eq!(results.parts[1].answer, "24933642");
+        // The borrowed slot is given back.
+        assert!(slots.try_take());
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        let mut rng = Rng::new(2022);
+
+        for entry in SOLUTIONS.iter() {
+            let Some(oracle) = entry.oracle() else {
+                continue;
+            };
+
+            for size in [1, 10, 100] {
+                let input = oracle.generate(&mut rng, size);
+
+                if let Err(error) = entry.solve(&input, &Part::ALL) {
+                    panic!("day {} generated an invalid input:\n{}", entry.day, error);
+                }
+            }
+        }
+    }
+}
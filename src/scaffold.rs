@@ -0,0 +1,220 @@
+use std::{fs, path::Path};
+
+use crate::{error::Error, registry};
+
+/// The module `aoc22 new` starts a day from, with `{day}` standing for the
+/// day number.
+const TEMPLATE: &str = r#"use crate::{error::ParseError, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(|l| l.to_owned()).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> usize {
+        0
+    }
+
+    fn part2(_input: &Self::Input) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    fn test_input() -> Vec<String> {
+        Day{day}::parse(&read_input("inputs/day{day}_test.txt")).unwrap()
+    }
+
+    #[test]
+    fn puzzle1_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day{day}::part1(&test_input), 0)
+    }
+
+    #[test]
+    fn puzzle2_test() {
+        let test_input = test_input();
+
+        assert_eq!(Day{day}::part2(&test_input), 0)
+    }
+}
+"#;
+
+pub fn template(day: u8) -> String {
+    TEMPLATE.replace("{day}", &day.to_string())
+}
+
+/// Creates the module, inputs and registry entry of a new day under `root`,
+/// returning the paths it wrote. Existing inputs are left alone.
+pub fn scaffold(root: &Path, day: u8) -> Result<Vec<String>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::usage(format!("invalid day: {}", day)));
+    }
+    if registry::find(day).is_some() {
+        return Err(Error::usage(format!("day {} is already registered", day)));
+    }
+
+    let module = root.join(format!("src/day{}.rs", day));
+    if module.exists() {
+        return Err(Error::usage(format!("{} already exists", module.display())));
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let lib = read(&lib_path)?;
+    let lib = insert_line(&lib, day, &format!("pub mod day{};", day), module_day)
+        .ok_or_else(|| not_found(&lib_path, day))?;
+
+    let registry_path = root.join("src/registry.rs");
+    let registry = read(&registry_path)?;
+    let registry = insert_line(
+        &registry,
+        day,
+        &format!("day{}::Day{},", day, day),
+        import_day,
+    )
+    .and_then(|registry| {
+        insert_line(
+            &registry,
+            day,
+            &format!("Entry::new::<Day{}>(),", day),
+            entry_day,
+        )
+    })
+    .ok_or_else(|| not_found(&registry_path, day))?;
+
+    write(&module, &template(day))?;
+    write(&lib_path, &lib)?;
+    write(&registry_path, &registry)?;
+    let mut written = vec![module, lib_path, registry_path];
+
+    for input in [
+        format!("inputs/day{}.txt", day),
+        format!("inputs/day{}_test.txt", day),
+    ] {
+        let input = root.join(input);
+        if !input.exists() {
+            write(&input, "")?;
+            written.push(input);
+        }
+    }
+
+    Ok(written
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect())
+}
+
+/// Inserts `line` next to the lines `day_of` recognises, keeping them sorted
+/// by day and copying their indentation.
+fn insert_line(text: &str, day: u8, line: &str, day_of: fn(&str) -> Option<u8>) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, l)| day_of(l).map(|d| (index, d)))
+        .collect();
+
+    let (index, neighbour) = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(index, _)) => (index + 1, index),
+        None => days.first().map(|&(index, _)| (index, index))?,
+    };
+
+    let indent = &lines[neighbour][..lines[neighbour].len() - lines[neighbour].trim_start().len()];
+    let line = format!("{}{}", indent, line);
+    lines.insert(index, &line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// `pub mod day8;`
+fn module_day(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+/// `day8::Day8,`
+fn import_day(line: &str) -> Option<u8> {
+    let (day, _) = line.trim().strip_prefix("day")?.split_once("::Day")?;
+
+    day.parse().ok()
+}
+
+/// `Entry::checked::<Day8>(),`
+fn entry_day(line: &str) -> Option<u8> {
+    let (_, day) = line.trim().strip_prefix("Entry::")?.split_once("::<Day")?;
+
+    day.split_once('>')?.0.parse().ok()
+}
+
+fn not_found(path: &Path, day: u8) -> Error {
+    Error::usage(format!(
+        "could not find where to register day {} in {}",
+        day,
+        path.display()
+    ))
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(&path.display().to_string(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    fs::write(path, contents).map_err(|e| Error::io(&path.display().to_string(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_input;
+
+    #[test]
+    fn template_test() {
+        let module = template(9);
+
+        assert!(module.contains("pub struct Day9;"));
+        assert!(module.contains("const DAY: u8 = 9;"));
+        assert!(module.contains("read_input(\"inputs/day9_test.txt\")"));
+    }
+
+    #[test]
+    fn register_test() {
+        let lib = insert_line(&read_input("src/lib.rs"), 9, "pub mod day9;", module_day).unwrap();
+        assert!(lib.contains("pub mod day8;\npub mod day9;\n"));
+
+        let registry = read_input("src/registry.rs");
+        let registry = insert_line(&registry, 9, "day9::Day9,", import_day).unwrap();
+        let registry = insert_line(&registry, 9, "Entry::new::<Day9>(),", entry_day).unwrap();
+        assert!(registry.contains("    day8::Day8,\n    day9::Day9,\n"));
+        assert!(registry.contains("    Entry::checked::<Day8>(),\n    Entry::new::<Day9>(),\n"));
+    }
+
+    #[test]
+    fn register_in_order_test() {
+        let lib = "pub mod day2;\npub mod day10;\n";
+
+        assert_eq!(
+            insert_line(lib, 9, "pub mod day9;", module_day).unwrap(),
+            "pub mod day2;\npub mod day9;\npub mod day10;\n"
+        );
+        assert_eq!(
+            insert_line(lib, 1, "pub mod day1;", module_day).unwrap(),
+            "pub mod day1;\npub mod day2;\npub mod day10;\n"
+        );
+        assert_eq!(insert_line("", 1, "pub mod day1;", module_day), None);
+    }
+}
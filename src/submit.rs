@@ -0,0 +1,408 @@
+use std::{fmt, fs, io, str::FromStr};
+
+use crate::{
+    error::{Error, ParseError},
+    fetch, http, Part,
+};
+
+pub const SUBMISSIONS_PATH: &str = "inputs/submissions.txt";
+
+const HEADER: &str = "# day part feedback answer";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    Correct,
+}
+
+impl Feedback {
+    /// Reads the verdict out of the page the server answers a submission
+    /// with. Pages that refuse the submission altogether are errors.
+    pub fn from_page(page: &str) -> Result<Self, Error> {
+        if page.contains("That's the right answer") {
+            Ok(Feedback::Correct)
+        } else if page.contains("your answer is too high") {
+            Ok(Feedback::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Feedback::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Ok(Feedback::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Err(Error::Http(
+                "an answer was submitted too recently, wait a bit".to_owned(),
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Err(Error::Http(
+                "this part is already solved or not unlocked yet".to_owned(),
+            ))
+        } else {
+            Err(Error::Http(
+                "could not find the verdict in the response".to_owned(),
+            ))
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            "correct" => Ok(Feedback::Correct),
+            _ => Err(Error::usage(format!(
+                "invalid feedback: {} (expected too-high, too-low, wrong or correct)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Feedback::TooHigh => write!(f, "too-high"),
+            Feedback::TooLow => write!(f, "too-low"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::Correct => write!(f, "correct"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub feedback: Feedback,
+    pub answer: String,
+}
+
+/// Every answer tried so far, kept in a plain text file with one
+/// `day part feedback answer` line per submission, oldest first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut submissions = Submissions::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (day, part, feedback, answer) = match fields[..] {
+                [day, part, feedback, answer] => (day, part, feedback, answer),
+                _ => {
+                    return Err(ParseError::new(
+                        index + 1,
+                        1,
+                        line,
+                        "expected `day part feedback answer`",
+                    ))
+                }
+            };
+
+            let day = day.parse::<u8>().map_err(|_| {
+                ParseError::at(index + 1, line, day, format!("invalid day `{}`", day))
+            })?;
+            let part = part.parse::<Part>().map_err(|_| {
+                ParseError::at(index + 1, line, part, format!("invalid part `{}`", part))
+            })?;
+            let feedback = feedback.parse::<Feedback>().map_err(|_| {
+                ParseError::at(
+                    index + 1,
+                    line,
+                    feedback,
+                    format!("invalid feedback `{}`", feedback),
+                )
+            })?;
+
+            submissions.record(Submission {
+                day,
+                part,
+                feedback,
+                answer: answer.to_owned(),
+            });
+        }
+
+        Ok(submissions)
+    }
+
+    /// A missing file is just an empty log.
+    pub fn load(path: &str) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text).map_err(|e| e.in_file(path))?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(Error::io(path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Error> {
+        fs::write(path, self.to_string()).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter()
+    }
+
+    /// The highest answer known to be too low and the lowest one known to be
+    /// too high, as far as the answers are numbers.
+    pub fn bounds(&self, day: u8, part: Part) -> (Option<i128>, Option<i128>) {
+        let numbers = |feedback: Feedback| {
+            self.iter()
+                .filter(move |s| s.day == day && s.part == part && s.feedback == feedback)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        (
+            numbers(Feedback::TooLow).max(),
+            numbers(Feedback::TooHigh).min(),
+        )
+    }
+
+    /// Reasons not to submit `answer`, judging by the earlier submissions.
+    pub fn warnings(&self, day: u8, part: Part, answer: &str) -> Vec<Warning> {
+        let mut warnings = vec![];
+        let earlier = || self.iter().filter(|s| s.day == day && s.part == part);
+
+        if let Some(correct) = earlier().find(|s| s.feedback == Feedback::Correct) {
+            if correct.answer == answer {
+                warnings.push(Warning::Accepted);
+            } else {
+                warnings.push(Warning::Differs {
+                    accepted: correct.answer.clone(),
+                });
+            }
+        }
+        if let Some(rejected) =
+            earlier().find(|s| s.answer == answer && s.feedback != Feedback::Correct)
+        {
+            warnings.push(Warning::Rejected(rejected.feedback));
+        }
+
+        if let Ok(number) = answer.parse::<i128>() {
+            let (low, high) = self.bounds(day, part);
+
+            if let Some(low) = low.filter(|&low| number <= low) {
+                warnings.push(Warning::TooLow { bound: low });
+            }
+            if let Some(high) = high.filter(|&high| number >= high) {
+                warnings.push(Warning::TooHigh { bound: high });
+            }
+        }
+
+        warnings
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    /// The same answer was accepted before.
+    Accepted,
+    Differs {
+        accepted: String,
+    },
+    Rejected(Feedback),
+    /// Not above an answer that was too low.
+    TooLow {
+        bound: i128,
+    },
+    /// Not below an answer that was too high.
+    TooHigh {
+        bound: i128,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::Accepted => write!(f, "already accepted"),
+            Warning::Differs { accepted } => {
+                write!(f, "differs from the accepted answer {}", accepted)
+            }
+            Warning::Rejected(feedback) => write!(f, "already rejected as {}", feedback),
+            Warning::TooLow { bound } => write!(f, "too low, {} already was", bound),
+            Warning::TooHigh { bound } => write!(f, "too high, {} already was", bound),
+        }
+    }
+}
+
+impl fmt::Display for Submissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+
+        for s in self.submissions.iter() {
+            writeln!(f, "{} {} {} {}", s.day, s.part, s.feedback, s.answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Sends answers to the server the inputs come from.
+pub struct Submitter {
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Submitter {
+    pub fn from_env() -> Self {
+        Submitter {
+            base_url: fetch::base_url(),
+            session: fetch::session(),
+        }
+    }
+
+    /// Answers are single words, as the submission log and the known
+    /// answers keep one per whitespace-separated field.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Feedback, Error> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            return Err(Error::usage(format!(
+                "invalid answer `{}`, expected a single word",
+                answer
+            )));
+        }
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| Error::usage(format!("set {} to submit answers", fetch::SESSION_VAR)))?;
+
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            fetch::YEAR,
+            day
+        );
+        let body = format!("level={}&answer={}", part, http::form_encode(answer));
+        let response = http::post(&url, &[("Cookie", &fetch::cookie(session)?)], &body)?;
+
+        if !response.is_success() {
+            return Err(Error::Http(format!(
+                "submitting day {} part {} failed with status {}",
+                day, part, response.status
+            )));
+        }
+
+        Feedback::from_page(&response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::testing;
+
+    const LOG: &str = "# day part feedback answer
+1 1 too-low 70000
+1 1 too-high 80000
+1 1 too-low 71000
+1 1 wrong 75000
+1 2 correct 212489
+";
+
+    #[test]
+    fn parse_test() {
+        let submissions = Submissions::parse(LOG).unwrap();
+
+        assert_eq!(submissions.to_string(), LOG);
+        assert_eq!(submissions.bounds(1, Part::One), (Some(71000), Some(80000)));
+        assert_eq!(submissions.bounds(1, Part::Two), (None, None));
+
+        let error = Submissions::parse("1 1 too-big 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut submissions = Submissions::parse(LOG).unwrap();
+        submissions.record(Submission {
+            day: 5,
+            part: Part::One,
+            feedback: Feedback::Correct,
+            answer: "CMZ".to_owned(),
+        });
+
+        let saved = submissions.to_string();
+        assert!(saved.ends_with("5 1 correct CMZ\n"));
+        assert_eq!(Submissions::parse(&saved).unwrap(), submissions);
+    }
+
+    #[test]
+    fn warnings_test() {
+        let submissions = Submissions::parse(LOG).unwrap();
+        let warnings = |part, answer| submissions.warnings(1, part, answer);
+
+        assert_eq!(warnings(Part::One, "71780"), vec![]);
+        assert_eq!(
+            warnings(Part::One, "70500"),
+            vec![Warning::TooLow { bound: 71000 }]
+        );
+        assert_eq!(
+            warnings(Part::One, "90000"),
+            vec![Warning::TooHigh { bound: 80000 }]
+        );
+        assert_eq!(
+            warnings(Part::One, "75000"),
+            vec![Warning::Rejected(Feedback::Wrong)]
+        );
+        assert_eq!(warnings(Part::Two, "212489"), vec![Warning::Accepted]);
+        assert_eq!(
+            warnings(Part::Two, "1"),
+            vec![Warning::Differs {
+                accepted: "212489".to_owned()
+            }]
+        );
+    }
+
+    #[test]
+    fn submit_test() {
+        let (base_url, server) = testing::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You gave an answer too recently.</p></article>",
+            ),
+        ]);
+        let submitter = Submitter {
+            base_url,
+            session: Some("secret".to_owned()),
+        };
+
+        assert_eq!(
+            submitter.submit(5, Part::One, "CMZ").unwrap(),
+            Feedback::TooHigh
+        );
+        assert!(submitter.submit(5, Part::Two, "A B").is_err());
+        assert_eq!(
+            submitter.submit(5, Part::Two, "A+B").unwrap(),
+            Feedback::Correct
+        );
+        assert!(submitter.submit(5, Part::Two, "MCD").is_err());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=secret\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=CMZ"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=2&answer=A%2BB"));
+    }
+}
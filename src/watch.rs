@@ -0,0 +1,171 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{error::Error, input, Part};
+
+/// What tells a file has changed: its modification time and size.
+type Stamp = (SystemTime, u64);
+
+fn stamp(path: &Path) -> Option<Stamp> {
+    let metadata = fs::metadata(path).ok()?;
+
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Polls files for changes through their metadata, so no platform file
+/// notification API is needed. A file appearing or disappearing counts as
+/// a change too.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<Stamp>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Watcher {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// The files that changed since the last call.
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+
+        for (path, last) in self.files.iter_mut() {
+            let current = stamp(path);
+            if current != *last {
+                *last = current;
+                changed.push(path.clone());
+            }
+        }
+
+        changed
+    }
+
+    /// Polls every `interval` until some files change.
+    pub fn wait(&mut self, interval: Duration) -> Vec<PathBuf> {
+        loop {
+            let changed = self.changed();
+            if !changed.is_empty() {
+                return changed;
+            }
+
+            thread::sleep(interval);
+        }
+    }
+}
+
+/// The day's module and its real and example inputs.
+pub fn paths(root: &Path, day: u8) -> Vec<PathBuf> {
+    vec![
+        root.join(format!("src/day{}.rs", day)),
+        input::default_path(day),
+        input::input_dir().join(format!("day{}_test.txt", day)),
+    ]
+}
+
+/// Runs cargo with `args`, capturing its output. Running the binary again
+/// through cargo is what picks up changes to the source.
+pub fn cargo(args: &[&str]) -> Result<Output, Error> {
+    Command::new("cargo")
+        .args(args)
+        .output()
+        .map_err(|e| Error::io("cargo", e))
+}
+
+/// The answers in the text output of `aoc22 run`.
+pub fn answers(output: &str) -> Vec<(Part, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.strip_prefix("Puzzle #")?.split_once(": ")?;
+
+            Some((part.parse().ok()?, answer.to_owned()))
+        })
+        .collect()
+}
+
+/// One line per part comparing `current` answers with the `previous` ones.
+pub fn diff(previous: &[(Part, String)], current: &[(Part, String)]) -> Vec<String> {
+    let find = |answers: &[(Part, String)], part| {
+        answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.clone())
+    };
+
+    Part::ALL
+        .into_iter()
+        .filter_map(|part| {
+            let line = match (find(previous, part), find(current, part)) {
+                (None, None) => return None,
+                (None, Some(answer)) => answer,
+                (Some(before), Some(after)) if before == after => format!("{} (unchanged)", after),
+                (Some(before), Some(after)) => format!("{} (was {})", after, before),
+                (Some(before), None) => format!("no answer (was {})", before),
+            };
+
+            Some(format!("part {}: {}", part, line))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn watcher_test() {
+        let path = env::temp_dir().join(format!("aoc22-watch-{}.txt", process::id()));
+        let _ = fs::remove_file(&path);
+        let mut watcher = Watcher::new(vec![path.clone()]);
+
+        assert!(watcher.changed().is_empty());
+        fs::write(&path, "1").unwrap();
+        assert_eq!(watcher.changed(), vec![path.clone()]);
+        assert!(watcher.changed().is_empty());
+        fs::write(&path, "12").unwrap();
+        assert_eq!(watcher.wait(Duration::from_millis(1)), vec![path.clone()]);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.changed(), vec![path]);
+    }
+
+    #[test]
+    fn diff_test() {
+        let previous = answers("Puzzle #1: 24000\nPuzzle #2: 45000\n");
+        let current = answers("warning\nPuzzle #1: 24000\nPuzzle #2: 41000\n");
+
+        assert_eq!(
+            current,
+            vec![
+                (Part::One, "24000".to_owned()),
+                (Part::Two, "41000".to_owned())
+            ]
+        );
+        assert_eq!(
+            diff(&previous, &current),
+            vec!["part 1: 24000 (unchanged)", "part 2: 41000 (was 45000)"]
+        );
+        assert_eq!(diff(&[], &current[..1]), vec!["part 1: 24000"]);
+        assert_eq!(
+            diff(&current, &[]),
+            vec![
+                "part 1: no answer (was 24000)",
+                "part 2: no answer (was 41000)"
+            ]
+        );
+    }
+}